@@ -210,3 +210,231 @@ macro_rules! ansi_clear_code {
     };
 }
 // endregion
+
+// region: parse
+/// Incremental parser that splits text into plain text and ANSI escape sequences (CSI, OSC, ...)
+///
+/// The state is kept between calls of [`AnsiScanner::segments`],
+/// so an escape sequence that is split over multiple writes is still recognised.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AnsiScanner {
+    state: AnsiState,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum AnsiState {
+    #[default]
+    Text,
+    /// after `ESC`
+    Escape,
+    /// after `ESC` and intermediate bytes, such as `ESC ( B`
+    EscapeIntermediate,
+    /// after `ESC [`, ends with a final byte
+    Csi,
+    /// after `ESC ]` (or `ESC P`, `ESC X`, `ESC ^`, `ESC _`), ends with `BEL` or `ESC \`
+    String,
+    /// after `ESC` inside of a string
+    StringEscape,
+}
+
+/// Part of a str, see [`AnsiScanner::segments`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiSegment<'s> {
+    Text(&'s str),
+    /// escape sequence or part of one
+    Escape(&'s str),
+}
+
+impl AnsiScanner {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: AnsiState::Text,
+        }
+    }
+
+    /// Whether the last str ended in the middle of an escape sequence
+    #[inline]
+    #[must_use]
+    pub const fn is_in_escape(&self) -> bool {
+        !matches!(self.state, AnsiState::Text)
+    }
+
+    #[inline]
+    pub fn segments<'a, 's>(&'a mut self, s: &'s str) -> AnsiSegments<'a, 's> {
+        AnsiSegments {
+            scanner: self,
+            rest: s,
+        }
+    }
+
+    /// Returns the index after the end of the escape sequence, or [`None`] if it continues after `bytes`.
+    ///
+    /// An invalid byte ends the sequence without being part of it, so the end is always on a char boundary.
+    fn escape_end(&mut self, bytes: &[u8]) -> Option<usize> {
+        const ESC: u8 = 0x1B;
+        const BEL: u8 = 0x07;
+
+        for (i, &b) in bytes.iter().enumerate() {
+            self.state = match self.state {
+                AnsiState::Text => {
+                    if b == ESC {
+                        AnsiState::Escape
+                    } else {
+                        return Some(i);
+                    }
+                }
+                AnsiState::Escape => match b {
+                    b'[' => AnsiState::Csi,
+                    b']' | b'P' | b'X' | b'^' | b'_' => AnsiState::String,
+                    0x20..=0x2F => AnsiState::EscapeIntermediate,
+                    0x30..=0x7E => {
+                        self.state = AnsiState::Text;
+                        return Some(i + 1);
+                    }
+                    _ => {
+                        self.state = AnsiState::Text;
+                        return Some(i);
+                    }
+                },
+                AnsiState::EscapeIntermediate => match b {
+                    0x20..=0x2F => AnsiState::EscapeIntermediate,
+                    0x30..=0x7E => {
+                        self.state = AnsiState::Text;
+                        return Some(i + 1);
+                    }
+                    _ => {
+                        self.state = AnsiState::Text;
+                        return Some(i);
+                    }
+                },
+                AnsiState::Csi => match b {
+                    0x20..=0x3F => AnsiState::Csi,
+                    0x40..=0x7E => {
+                        self.state = AnsiState::Text;
+                        return Some(i + 1);
+                    }
+                    _ => {
+                        self.state = AnsiState::Text;
+                        return Some(i);
+                    }
+                },
+                AnsiState::String => match b {
+                    BEL => {
+                        self.state = AnsiState::Text;
+                        return Some(i + 1);
+                    }
+                    ESC => AnsiState::StringEscape,
+                    _ => AnsiState::String,
+                },
+                AnsiState::StringEscape => match b {
+                    b'\\' => {
+                        self.state = AnsiState::Text;
+                        return Some(i + 1);
+                    }
+                    ESC => AnsiState::StringEscape,
+                    _ => AnsiState::String,
+                },
+            };
+        }
+        None
+    }
+}
+
+/// Iterator over the [`AnsiSegment`]s of a str, see [`AnsiScanner::segments`]
+pub struct AnsiSegments<'a, 's> {
+    scanner: &'a mut AnsiScanner,
+    rest: &'s str,
+}
+
+impl<'s> Iterator for AnsiSegments<'_, 's> {
+    type Item = AnsiSegment<'s>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let rest = self.rest;
+        let is_escape = self.scanner.is_in_escape() || rest.starts_with('\x1B');
+        let end = if is_escape {
+            self.scanner
+                .escape_end(rest.as_bytes())
+                .unwrap_or(rest.len())
+        } else {
+            rest.find('\x1B').unwrap_or(rest.len())
+        };
+        // the end is always on a char boundary, see `escape_end`
+        let (segment, rest) = unsafe { (rest.get_unchecked(..end), rest.get_unchecked(end..)) };
+        self.rest = rest;
+        Some(if is_escape {
+            AnsiSegment::Escape(segment)
+        } else {
+            AnsiSegment::Text(segment)
+        })
+    }
+}
+// endregion
+
+// region: detect
+/// Whether colours and other escape sequences should be written to `stream`
+///
+/// 1. `CLICOLOR_FORCE` is set and not `0`: `true`
+/// 2. `NO_COLOR` is set and not empty: `false`
+/// 3. `TERM` is `dumb`: `false`
+/// 4. otherwise whether `stream` is a terminal
+#[cfg(feature = "std")]
+#[must_use]
+pub fn supports_color<T>(stream: &T) -> bool
+where
+    T: std::io::IsTerminal + ?Sized,
+{
+    if std::env::var_os("CLICOLOR_FORCE").is_some_and(|v| v != "0") {
+        return true;
+    }
+    if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    if std::env::var_os("TERM").is_some_and(|v| v == "dumb") {
+        return false;
+    }
+    stream.is_terminal()
+}
+// endregion
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{AnsiScanner, AnsiSegment};
+
+    #[test]
+    fn segments() {
+        let mut scanner = AnsiScanner::new();
+        let segments: Vec<_> = scanner
+            .segments("a\x1B[38;5;9mred\x1B[39m\x1B]0;title\x07b\x1B(Bc")
+            .collect();
+        assert_eq!(
+            segments,
+            [
+                AnsiSegment::Text("a"),
+                AnsiSegment::Escape("\x1B[38;5;9m"),
+                AnsiSegment::Text("red"),
+                AnsiSegment::Escape("\x1B[39m"),
+                AnsiSegment::Escape("\x1B]0;title\x07"),
+                AnsiSegment::Text("b"),
+                AnsiSegment::Escape("\x1B(B"),
+                AnsiSegment::Text("c"),
+            ]
+        );
+
+        // split over multiple writes
+        let segments: Vec<_> = scanner.segments("x\x1B[3").collect();
+        assert_eq!(segments, [AnsiSegment::Text("x"), AnsiSegment::Escape("\x1B[3")]);
+        assert!(scanner.is_in_escape());
+        let segments: Vec<_> = scanner.segments("1mé").collect();
+        assert_eq!(segments, [AnsiSegment::Escape("1m"), AnsiSegment::Text("é")]);
+
+        // invalid sequence doesn't swallow the next char
+        let segments: Vec<_> = scanner.segments("\x1Bé").collect();
+        assert_eq!(segments, [AnsiSegment::Escape("\x1B"), AnsiSegment::Text("é")]);
+    }
+}
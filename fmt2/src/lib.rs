@@ -17,6 +17,10 @@
 #![cfg_attr(feature = "ascii", feature(ascii_char))]
 #![cfg_attr(feature = "ascii_char_variants", feature(ascii_char_variants))]
 
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc;
+
 pub mod ansi;
 pub mod macros;
 pub mod str;
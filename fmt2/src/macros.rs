@@ -33,6 +33,24 @@ macro_rules! get_write_to_from_fmt_args {
 		use ::core::iter::IntoIterator as _;
 		$value.into_iter().fmt_iterator()
     }};
+    { $value:expr; list $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @list($crate::write_to::list::List::new(::core::iter::IntoIterator::into_iter(&$value))) $($mods)* }
+    };
+    { $value:expr; @list($list:expr) and $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @list($list.and()) $($mods)* }
+    };
+    { $value:expr; @list($list:expr) or $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @list($list.or()) $($mods)* }
+    };
+    { $value:expr; @list($list:expr) oxford $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @list($list.oxford()) $($mods)* }
+    };
+    { $value:expr; @list($list:expr) max $max:tt $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @list($list.max($max)) $($mods)* }
+    };
+    { $value:expr; @list($list:expr) } => {
+		&$list
+    };
     { $value:expr; } => {{
 		#[allow(unused_imports)]
         use $crate::write_to::Fmt as _;
@@ -53,6 +71,95 @@ macro_rules! get_write_to_from_fmt_args {
         use $crate::write_to::FmtHex as _;
 		$value.fmt_hex()
     }};
+    { $value:expr; b $($mods:tt)+ } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int(2, 0) $($mods)+ }
+    };
+    { $value:expr; o $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int(8, 0) $($mods)* }
+    };
+    { $value:expr; d $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int(10, 0) $($mods)* }
+    };
+    { $value:expr; h $($mods:tt)+ } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int(16, 0) $($mods)+ }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) plus $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::PLUS)) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) lower $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::LOWER)) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) zero $WIDTH:literal $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::zero($WIDTH))) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) prefix $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::PREFIX)) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) full $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::FULL)) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) space $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::SPACE)) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) group2 $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, $OPTIONS) group 2 $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) group3 $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, $OPTIONS) group 3 $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) group4 $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, $OPTIONS) group 4 $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) group8 $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, $OPTIONS) group 8 $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) group $DIGITS:literal $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @int($RADIX, ($OPTIONS | $crate::write_to::int::options::group($DIGITS))) $($mods)* }
+    };
+    { $value:expr; @int($RADIX:expr, $OPTIONS:expr) } => {{
+		#[allow(non_camel_case_types)]
+		trait TempDeref_Unique_20261018_1617_b3kqd {
+			fn temp_deref_unique_20261018_1618_w0zrt(&self) -> &(impl $crate::write_to::int::FmtInt<{ $RADIX }, { $OPTIONS }> + ?Sized);
+		}
+		impl<T> TempDeref_Unique_20261018_1617_b3kqd for T where T: ?Sized + $crate::write_to::int::FmtInt<{ $RADIX }, { $OPTIONS }> {
+			#[inline]
+			fn temp_deref_unique_20261018_1618_w0zrt(&self) -> &(impl $crate::write_to::int::FmtInt<{ $RADIX }, { $OPTIONS }> + ?Sized) {
+				self
+			}
+		}
+		$crate::write_to::int::FmtInt::<{ $RADIX }, { $OPTIONS }>::fmt_int($value.temp_deref_unique_20261018_1618_w0zrt())
+    }};
+    { $value:expr; e $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @float($crate::write_to::float::options::EXP) $($mods)* }
+    };
+    { $value:expr; E $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @float($crate::write_to::float::options::UPPER_EXP) $($mods)* }
+    };
+    { $value:expr; @float($OPTIONS:expr) plus $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @float(($OPTIONS | $crate::write_to::float::options::PLUS)) $($mods)* }
+    };
+    { $value:expr; @float($OPTIONS:expr) .$PRECISION:literal $($mods:tt)* } => {
+		$crate::get_write_to_from_fmt_args! { $value; @float(($OPTIONS | $crate::write_to::float::options::precision($PRECISION))) $($mods)* }
+    };
+    { $value:expr; @float($OPTIONS:expr) } => {{
+		#[allow(non_camel_case_types)]
+		trait TempDeref_Unique_20261018_1704_n6hxe {
+			fn temp_deref_unique_20261018_1705_p1cua(&self) -> &(impl $crate::write_to::float::FmtFloat<{ $OPTIONS }> + ?Sized);
+		}
+		impl<T> TempDeref_Unique_20261018_1704_n6hxe for T where T: ?Sized + $crate::write_to::float::FmtFloat<{ $OPTIONS }> {
+			#[inline]
+			fn temp_deref_unique_20261018_1705_p1cua(&self) -> &(impl $crate::write_to::float::FmtFloat<{ $OPTIONS }> + ?Sized) {
+				self
+			}
+		}
+		$crate::write_to::float::FmtFloat::<{ $OPTIONS }>::fmt_float($value.temp_deref_unique_20261018_1705_p1cua())
+    }};
+    { $value:expr; or $default:expr } => {
+		&$crate::write_to::containers::Or::new(&$value, $default)
+    };
+    { $value:expr; sep $separator:expr } => {
+		&$crate::write_to::containers::Separated::new(&$value, $separator)
+    };
     { $value:expr; .$PRECISION:expr } => {{
 		#[allow(non_camel_case_types)]
 		trait TempDeref_Unique_20250129_1242_5jfkf {
@@ -66,6 +173,140 @@ macro_rules! get_write_to_from_fmt_args {
 		}
 		$crate::write_to::FmtPrecision::<{ $PRECISION }>::fmt_precision($value.temp_deref_unique_20250129_1245_hr3un())
     }};
+    { $value:expr; json } => {{
+		#[allow(unused_imports)]
+		use $crate::write::escape::FmtJson as _;
+		$value.fmt_json()
+    }};
+    { $value:expr; html } => {{
+		#[allow(unused_imports)]
+		use $crate::write::escape::FmtHtml as _;
+		$value.fmt_html()
+    }};
+    { $value:expr; shell } => {{
+		#[allow(unused_imports)]
+		use $crate::write::escape::FmtShell as _;
+		$value.fmt_shell()
+    }};
+    { $value:expr; csv } => {{
+		#[allow(unused_imports)]
+		use $crate::write::escape::FmtCsv as _;
+		$value.fmt_csv()
+    }};
+    { $value:expr; safe } => {{
+		#[allow(unused_imports)]
+		use $crate::write::sanitize::FmtSanitized as _;
+		$value.fmt_sanitized()
+    }};
+    { $value:expr; safe pictures } => {{
+		#[allow(unused_imports)]
+		use $crate::write::sanitize::FmtSanitizedPictures as _;
+		$value.fmt_sanitized_pictures()
+    }};
+    { $value:expr; truncate $WIDTH:expr } => {{
+		#[allow(non_camel_case_types)]
+		trait TempDeref_Unique_20261018_1412_q8zlw {
+			fn temp_deref_unique_20261018_1413_c2mfe(&self) -> &(impl $crate::write::truncate::FmtTruncate<{ $WIDTH }> + ?Sized);
+		}
+		impl<T> TempDeref_Unique_20261018_1412_q8zlw for T where T: ?Sized + $crate::write::truncate::FmtTruncate<{ $WIDTH }> {
+			#[inline]
+			fn temp_deref_unique_20261018_1413_c2mfe(&self) -> &(impl $crate::write::truncate::FmtTruncate<{ $WIDTH }> + ?Sized) {
+				self
+			}
+		}
+		$crate::write::truncate::FmtTruncate::<{ $WIDTH }>::fmt_truncate($value.temp_deref_unique_20261018_1413_c2mfe())
+    }};
+    { $value:expr; < $WIDTH:literal } => {
+		$crate::get_write_to_from_fmt_args! { $value; @pad({ $WIDTH }, '<', ' ') }
+    };
+    { $value:expr; $FILL:literal < $WIDTH:literal } => {
+		$crate::get_write_to_from_fmt_args! { $value; @pad({ $WIDTH }, '<', { $crate::write_to::pad::fill_from_literal_internal(stringify!($FILL)) }) }
+    };
+    { $value:expr; < ($width:expr) } => {
+		&$crate::write_to::pad::PadWidth::<_, '<', ' '>::new(
+			{
+				#[allow(unused_imports)]
+				use $crate::write_to::Fmt as _;
+				$value.fmt()
+			},
+			$width,
+		)
+    };
+    { $value:expr; $FILL:literal < ($width:expr) } => {
+		&$crate::write_to::pad::PadWidth::<_, '<', { $crate::write_to::pad::fill_from_literal_internal(stringify!($FILL)) }>::new(
+			{
+				#[allow(unused_imports)]
+				use $crate::write_to::Fmt as _;
+				$value.fmt()
+			},
+			$width,
+		)
+    };
+    { $value:expr; > $WIDTH:literal } => {
+		$crate::get_write_to_from_fmt_args! { $value; @pad({ $WIDTH }, '>', ' ') }
+    };
+    { $value:expr; $FILL:literal > $WIDTH:literal } => {
+		$crate::get_write_to_from_fmt_args! { $value; @pad({ $WIDTH }, '>', { $crate::write_to::pad::fill_from_literal_internal(stringify!($FILL)) }) }
+    };
+    { $value:expr; > ($width:expr) } => {
+		&$crate::write_to::pad::PadWidth::<_, '>', ' '>::new(
+			{
+				#[allow(unused_imports)]
+				use $crate::write_to::Fmt as _;
+				$value.fmt()
+			},
+			$width,
+		)
+    };
+    { $value:expr; $FILL:literal > ($width:expr) } => {
+		&$crate::write_to::pad::PadWidth::<_, '>', { $crate::write_to::pad::fill_from_literal_internal(stringify!($FILL)) }>::new(
+			{
+				#[allow(unused_imports)]
+				use $crate::write_to::Fmt as _;
+				$value.fmt()
+			},
+			$width,
+		)
+    };
+    { $value:expr; ^ $WIDTH:literal } => {
+		$crate::get_write_to_from_fmt_args! { $value; @pad({ $WIDTH }, '^', ' ') }
+    };
+    { $value:expr; $FILL:literal ^ $WIDTH:literal } => {
+		$crate::get_write_to_from_fmt_args! { $value; @pad({ $WIDTH }, '^', { $crate::write_to::pad::fill_from_literal_internal(stringify!($FILL)) }) }
+    };
+    { $value:expr; ^ ($width:expr) } => {
+		&$crate::write_to::pad::PadWidth::<_, '^', ' '>::new(
+			{
+				#[allow(unused_imports)]
+				use $crate::write_to::Fmt as _;
+				$value.fmt()
+			},
+			$width,
+		)
+    };
+    { $value:expr; $FILL:literal ^ ($width:expr) } => {
+		&$crate::write_to::pad::PadWidth::<_, '^', { $crate::write_to::pad::fill_from_literal_internal(stringify!($FILL)) }>::new(
+			{
+				#[allow(unused_imports)]
+				use $crate::write_to::Fmt as _;
+				$value.fmt()
+			},
+			$width,
+		)
+    };
+    { $value:expr; @pad($WIDTH:expr, $ALIGN:expr, $FILL:expr) } => {{
+		#[allow(non_camel_case_types)]
+		trait TempDeref_Unique_20261018_1532_x0pqa {
+			fn temp_deref_unique_20261018_1533_m4vne(&self) -> &(impl $crate::write_to::pad::FmtPad<{ $WIDTH }, { $ALIGN }, { $FILL }> + ?Sized);
+		}
+		impl<T> TempDeref_Unique_20261018_1532_x0pqa for T where T: ?Sized + $crate::write_to::pad::FmtPad<{ $WIDTH }, { $ALIGN }, { $FILL }> {
+			#[inline]
+			fn temp_deref_unique_20261018_1533_m4vne(&self) -> &(impl $crate::write_to::pad::FmtPad<{ $WIDTH }, { $ALIGN }, { $FILL }> + ?Sized) {
+				self
+			}
+		}
+		$crate::write_to::pad::FmtPad::<{ $WIDTH }, { $ALIGN }, { $FILL }>::fmt_pad($value.temp_deref_unique_20261018_1533_m4vne())
+    }};
     { $value:expr; std } => {{
 		#[allow(unused_imports)]
 		use $crate::write_to::FmtStdDisplay as _;
@@ -234,6 +475,66 @@ macro_rules! write_fmt_single_internal {
 		}
 	}};
 
+	($writer:expr => (@..join($iterator:expr => $join:tt => last $last:tt $(pair $pair:tt)? => |$name:ident $(: $ty:ty)?| $($fmt:tt)*)) => $handle_error_args:tt) => {{
+		use ::core::iter::IntoIterator as _;
+
+		let mut iterator = ::core::iter::Iterator::peekable($iterator.into_iter());
+		let mut index: usize = 0;
+		while let ::core::option::Option::Some($name) = ::core::iter::Iterator::next(&mut iterator) {
+			let $name $(: $ty)? = $name;
+			if index == 0 {
+			} else if ::core::option::Option::is_some(&iterator.peek()) {
+				$crate::fmt_internal! {
+					input: { $join },
+					output: {},
+					args: {
+						mode: nocapture write_inner {
+							writer: $writer,
+							handle_error_args: $handle_error_args,
+						},
+						ends_in_newline: false,
+					}
+				}
+			} else $(if index == 1 {
+				$crate::fmt_internal! {
+					input: { $pair },
+					output: {},
+					args: {
+						mode: nocapture write_inner {
+							writer: $writer,
+							handle_error_args: $handle_error_args,
+						},
+						ends_in_newline: false,
+					}
+				}
+			} else)? {
+				$crate::fmt_internal! {
+					input: { $last },
+					output: {},
+					args: {
+						mode: nocapture write_inner {
+							writer: $writer,
+							handle_error_args: $handle_error_args,
+						},
+						ends_in_newline: false,
+					}
+				}
+			}
+			$crate::fmt_internal! {
+				input: { $($fmt)* },
+				output: {},
+				args: {
+					mode: nocapture write_inner {
+						writer: $writer,
+						handle_error_args: $handle_error_args,
+					},
+					ends_in_newline: false,
+				}
+			}
+			index += 1;
+		}
+	}};
+
 	($writer:expr => [$("", )*] => $handle_error_args:tt) => {{
 		::core::compile_error!("unreachable. dev error or bug using macro");
 	}};
@@ -275,6 +576,57 @@ macro_rules! len_hint_fmt_single_internal {
 	((@..join($iterator:expr => $join:expr => |$name:ident $(: $ty:ty)?| $($fmt:tt)*))) => {{
 		0
 	}};
+	((@..join($iterator:expr => $join:tt => last $last:tt $(pair $pair:tt)? => |$name:ident $(: $ty:ty)?| $($fmt:tt)*))) => {{
+		use ::core::iter::IntoIterator as _;
+
+		// a clone is iterated, so the iterator can still be written afterwards
+		let mut iterator = ::core::iter::Iterator::peekable(::core::clone::Clone::clone(&$iterator).into_iter());
+		let mut index: usize = 0;
+		let mut len: usize = 0;
+		while let ::core::option::Option::Some($name) = ::core::iter::Iterator::next(&mut iterator) {
+			#[allow(unused_variables)]
+			let $name $(: $ty)? = $name;
+			if index == 0 {
+			} else if ::core::option::Option::is_some(&iterator.peek()) {
+				len += $crate::fmt_internal! {
+					input: { $join },
+					output: {},
+					args: {
+						mode: nocapture len_hint {},
+						ends_in_newline: false,
+					}
+				};
+			} else $(if index == 1 {
+				len += $crate::fmt_internal! {
+					input: { $pair },
+					output: {},
+					args: {
+						mode: nocapture len_hint {},
+						ends_in_newline: false,
+					}
+				};
+			} else)? {
+				len += $crate::fmt_internal! {
+					input: { $last },
+					output: {},
+					args: {
+						mode: nocapture len_hint {},
+						ends_in_newline: false,
+					}
+				};
+			}
+			len += $crate::fmt_internal! {
+					input: { $($fmt)* },
+					output: {},
+					args: {
+						mode: nocapture len_hint {},
+						ends_in_newline: false,
+					}
+				};
+			index += 1;
+		}
+		len
+	}};
 
 	([$("", )*]) => {{
 		::core::compile_error!("unreachable. dev error or bug using macro");
@@ -890,6 +1242,18 @@ macro_rules! fmt_internal {
 		)*
 	};
 
+	// (mode = nocapture len_hint)
+	{
+		input: {},
+		output: { $(internal $fmt:tt)* },
+		args: {
+			mode: nocapture len_hint {},
+			ends_in_newline: $ends_in_newline:expr,
+		}
+	} => {
+		$crate::len_hint_fmt_internal!($($fmt)*)
+	};
+
 	// (mode = nocapture generate_fn)
 	{
 		input: {},
@@ -1012,7 +1376,7 @@ macro_rules! fmt_internal {
 			ends_in_newline: $ends_in_newline:expr,
 		}
 	} => {{
-		let mut string = String::with_capacity($crate::len_hint_fmt_internal!($($fmt)*));
+		let mut string = $crate::alloc::string::String::with_capacity($crate::len_hint_fmt_internal!($($fmt)*));
 		$(
 			$crate::write_fmt_single_internal! { &mut string => $fmt => { ! } }
 		)*
@@ -1289,6 +1653,18 @@ macro_rules! fmt {
 	{ (#err) => $($tt:tt)* } => {
 		$crate::fmt! { (::std::io::stderr()) => $($tt)* }
 	};
+	{ (? #auto) => $($tt:tt)* } => {
+		$crate::fmt! { (? $crate::write::strip_ansi::StripAnsi::auto(&mut ::std::io::stdout())) => $($tt)* }
+	};
+	{ (#auto) => $($tt:tt)* } => {
+		$crate::fmt! { ($crate::write::strip_ansi::StripAnsi::auto(&mut ::std::io::stdout())) => $($tt)* }
+	};
+	{ (? #err auto) => $($tt:tt)* } => {
+		$crate::fmt! { (? $crate::write::strip_ansi::StripAnsi::auto(&mut ::std::io::stderr())) => $($tt)* }
+	};
+	{ (#err auto) => $($tt:tt)* } => {
+		$crate::fmt! { ($crate::write::strip_ansi::StripAnsi::auto(&mut ::std::io::stderr())) => $($tt)* }
+	};
 	{ (? #lock) => $($tt:tt)* } => {
 		$crate::fmt! { (? std::io::Stdout::lock(&::std::io::stdout())) => $($tt)* }
 	};
@@ -1301,6 +1677,12 @@ macro_rules! fmt {
 	{ (#err lock) => $($tt:tt)* } => {
 		$crate::fmt! { (std::io::Stdout::lock(&::std::io::stderr())) => $($tt)* }
 	};
+	{ (? #io $writer:expr) => $($tt:tt)* } => {
+		$crate::fmt! { (? $crate::write::io::IoWriter::new($writer)) => $($tt)* }
+	};
+	{ (#io $writer:expr) => $($tt:tt)* } => {
+		$crate::fmt! { ($crate::write::io::IoWriter::new($writer)) => $($tt)* }
+	};
 	{ (? $writer:expr) => $($tt:tt)* } => {
 		$crate::fmt_internal! {
 			input: { $($tt)* },
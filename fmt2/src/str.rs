@@ -144,6 +144,7 @@ macro_rules! impl_const_str_for_get_value_internal {
     };
 }
 
+/// Implement [`core::fmt::Display`] for types that are formatted as a [`str`] (see [`FmtStr`]), width and alignment are supported
 #[macro_export]
 macro_rules! impl_display_for_str {
 	{ $($name:ty),* $(,)? } => {
@@ -151,7 +152,7 @@ macro_rules! impl_display_for_str {
 			impl ::core::fmt::Display for $name {
 				#[inline]
 				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-					::core::fmt::Formatter::write_str(f, $crate::write_to::Str::str(self))
+					::core::fmt::Formatter::pad(f, $crate::str::FmtStr::fmt_str(self))
 				}
 			}
 		)*
@@ -2,14 +2,16 @@ use super::{utils::to_prompt_result_try, Back, PromptWith, Quit, Result as Promp
 
 use crate::{
     terminal::{event, screen_area::ScreenArea},
-    write::Write,
+    write::{Write, sanitize::FmtSanitizedIf},
     write_to::Fmt,
 };
 
 use core::ops::ControlFlow::{Break, Continue};
 
+/// If `SANITIZE = true`, the info and additional info are [`Sanitized`](crate::write::sanitize::Sanitized),
+/// see [`Info::sanitized`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Info<'i, 'a, I: ?Sized = str, A: ?Sized = str> {
+pub struct Info<'i, 'a, I: ?Sized = str, A: ?Sized = str, const SANITIZE: bool = false> {
     info: &'i I,
     additional: Option<&'a A>,
 }
@@ -24,6 +26,18 @@ impl<'i, 'a, I: ?Sized, A: ?Sized> Info<'i, 'a, I, A> {
     pub const fn new_with(info: &'i I, additional: Option<&'a A>) -> Self {
         Self { info, additional }
     }
+}
+
+impl<'i, 'a, I: ?Sized, A: ?Sized, const SANITIZE: bool> Info<'i, 'a, I, A, SANITIZE> {
+    /// Replace control chars in the info, for text from untrusted sources
+    #[inline]
+    #[must_use]
+    pub const fn sanitized(self) -> Info<'i, 'a, I, A, true> {
+        Info {
+            info: self.info,
+            additional: self.additional,
+        }
+    }
 
     #[must_use]
     #[inline]
@@ -49,7 +63,7 @@ impl<'i, I: ?Sized> Info<'i, '_, I> {
     }
 }
 
-impl<W, I, A> PromptWith<W> for Info<'_, '_, I, A>
+impl<W, I, A, const SANITIZE: bool> PromptWith<W> for Info<'_, '_, I, A, SANITIZE>
 where
     W: Write<Error = std::io::Error>,
     I: Fmt + ?Sized,
@@ -62,11 +76,11 @@ where
 
         // print info
         to_prompt_result_try!(
-            crate::fmt_prompt_question! { (? screen_area) => {self.info}, start = [@cursor_move_to_x(@start)], end = ln }
+            crate::fmt_prompt_question! { (? screen_area) => {@info = FmtSanitizedIf::<SANITIZE>::fmt_sanitized_if(self.info)}, start = [@cursor_move_to_x(@start)], end = ln }
         );
         // print additional
         if let Some(a) = self.additional {
-            to_prompt_result_try!(crate::fmt! { (? screen_area) => {a} ln });
+            to_prompt_result_try!(crate::fmt! { (? screen_area) => {@a = FmtSanitizedIf::<SANITIZE>::fmt_sanitized_if(a)} ln });
         }
         to_prompt_result_try!(
             crate::fmt_prompt_help! { (? screen_area) => [@[crate::INFO_HELP!()]]}
@@ -3,7 +3,7 @@ use super::{utils::to_prompt_result_try, Back, PromptWith, Quit, Result as Promp
 use crate::{
     str::FmtStr,
     terminal::{event, screen_area::ScreenArea},
-    write::Write,
+    write::{Write, sanitize::FmtSanitizedIf},
 };
 
 use core::ops::{
@@ -117,13 +117,16 @@ impl<'c, T> TryFrom<&'c [T]> for &'c Choices<T> {
     }
 }
 
+/// If `SANITIZE = true`, the question and choices are [`Sanitized`](crate::write::sanitize::Sanitized),
+/// see [`Select::sanitized`]
 #[derive(Debug, Clone, Copy)]
-pub struct Select<'q, 'c, T, const IS_MAIN_MENU: bool = false> {
+pub struct Select<'q, 'c, T, const IS_MAIN_MENU: bool = false, const SANITIZE: bool = false> {
     question: &'q str,
     choices: &'c Choices<T>,
 }
 
-impl<'q, 'c, T, U, const IS_MAIN_MENU: bool> Select<'q, 'c, T, IS_MAIN_MENU>
+impl<'q, 'c, T, U, const IS_MAIN_MENU: bool, const SANITIZE: bool>
+    Select<'q, 'c, T, IS_MAIN_MENU, SANITIZE>
 where
     T: Deref<Target = U>,
     U: FmtStr + ?Sized,
@@ -132,6 +135,34 @@ where
         self.choices.check()
     }
 
+    /// Replace control chars in the question and choices, for text from untrusted sources
+    #[inline]
+    #[must_use]
+    pub const fn sanitized(self) -> Select<'q, 'c, T, IS_MAIN_MENU, true> {
+        Select {
+            question: self.question,
+            choices: self.choices,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn question(&self) -> &'q str {
+        self.question
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn choices(&self) -> &'c Choices<T> {
+        self.choices
+    }
+}
+
+impl<'q, 'c, T, U, const IS_MAIN_MENU: bool> Select<'q, 'c, T, IS_MAIN_MENU>
+where
+    T: Deref<Target = U>,
+    U: FmtStr + ?Sized,
+{
     #[inline]
     #[must_use]
     pub const fn new(question: &'q str, choices: &'c Choices<T>) -> Self {
@@ -152,24 +183,13 @@ where
     pub const unsafe fn new_unchecked(question: &'q str, choices: &'c [T]) -> Self {
         Self {
             question,
-            choices: Choices::new_unchecked(choices),
+            choices: unsafe { Choices::new_unchecked(choices) },
         }
     }
-
-    #[must_use]
-    #[inline]
-    pub const fn question(&self) -> &'q str {
-        self.question
-    }
-
-    #[inline]
-    #[must_use]
-    pub const fn choices(&self) -> &'c Choices<T> {
-        self.choices
-    }
 }
 
-impl<'c, W, T, U, const IS_MAIN_MENU: bool> PromptWith<W> for Select<'_, 'c, T, IS_MAIN_MENU>
+impl<'c, W, T, U, const IS_MAIN_MENU: bool, const SANITIZE: bool> PromptWith<W>
+    for Select<'_, 'c, T, IS_MAIN_MENU, SANITIZE>
 where
     W: Write<Error = std::io::Error>,
     T: Deref<Target = U>,
@@ -193,7 +213,7 @@ where
 				$crate::fmt! { $args =>
 					$($start)?
 					@[$crate::terminal::prompt::consts::UNCHOSEN_CHOICE_LINE_PREFIX!()]
-					{@unique_20250115_1039 = $choice; str first_line}
+					{@unique_20250115_1039 = $crate::write::sanitize::FmtSanitizedIf::<SANITIZE>::fmt_sanitized_if($crate::utils::first_line($choice.fmt_str()))}
 					$($end)?
 				}
 			};
@@ -205,7 +225,7 @@ where
 					$($start)?
 					@fg(@cyan) [
 						@[$crate::terminal::prompt::consts::CHOSEN_CHOICE_LINE_PREFIX!()]
-						{@unique_20250115_1040 = $choice; str first_line}
+						{@unique_20250115_1040 = $crate::write::sanitize::FmtSanitizedIf::<SANITIZE>::fmt_sanitized_if($crate::utils::first_line($choice.fmt_str()))}
 					]
 					$($end)?
 				}
@@ -228,7 +248,7 @@ where
 
         // ask question
         to_prompt_result_try!(
-            crate::fmt_prompt_question! { (? screen_area) => {self.question}, start = [@cursor_move_to_x(@start)], end = ln }
+            crate::fmt_prompt_question! { (? screen_area) => {@question = FmtSanitizedIf::<SANITIZE>::fmt_sanitized_if(self.question)}, start = [@cursor_move_to_x(@start)], end = ln }
         );
 
         let choices_row_offset = screen_area.cursor_y;
@@ -91,7 +91,7 @@ where
             .try_into()
             .map(|n| self.cursor_y.saturating_add(n))
             .unwrap_or(u16::MAX);
-        self.writer.write(s)
+        self.writer.write_str(s)
     }
 
     fn flush_hint(&mut self) {
@@ -39,6 +39,57 @@ pub fn first_line_no_debug_assertion(s: &str) -> &str {
     }
 }
 
+/// Largest index `<= index` that is on a char boundary, so `&s[..i]` never splits a char
+#[inline]
+#[must_use]
+pub const fn floor_char_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    let bytes = s.as_bytes();
+    let mut i = index;
+    // continuation bytes look like 0b10xx_xxxx
+    while i > 0 && (bytes[i] as i8) < -0x40 {
+        i -= 1;
+    }
+    i
+}
+
+/// Width of a char in terminal columns
+///
+/// Without the `unicode_width` feature every char that isn't a control char is 1 column wide.
+#[inline]
+#[must_use]
+pub fn char_width(c: char) -> usize {
+    #[cfg(feature = "unicode_width")]
+    {
+        unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+    #[cfg(not(feature = "unicode_width"))]
+    {
+        usize::from(!c.is_control())
+    }
+}
+
+/// Width of text in terminal columns, see [`char_width`]. Doesn't ignore ANSI escape sequences, see [`display_width`]
+#[inline]
+#[must_use]
+pub fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Width of text in terminal columns, ignoring ANSI escape sequences
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    crate::ansi::AnsiScanner::new()
+        .segments(s)
+        .map(|segment| match segment {
+            crate::ansi::AnsiSegment::Text(text) => str_width(text),
+            crate::ansi::AnsiSegment::Escape(_) => 0,
+        })
+        .sum()
+}
+
 #[allow(unused)]
 #[cfg(test)]
 mod tests {
@@ -56,4 +107,22 @@ mod tests {
         assert_eq!(first_line_no_debug_assertion("\n"), "");
         assert_eq!(first_line_no_debug_assertion(""), "");
     }
+
+    #[test]
+    fn test_floor_char_boundary() {
+        assert_eq!(floor_char_boundary("abc", 2), 2);
+        assert_eq!(floor_char_boundary("abc", 5), 3);
+        assert_eq!(floor_char_boundary("aé", 2), 1);
+        assert_eq!(floor_char_boundary("aé", 3), 3);
+        assert_eq!(floor_char_boundary("€", 2), 0);
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("\x1B[38;5;9mabc\x1B[39m"), 3);
+        assert_eq!(display_width("a\nb"), 2);
+        #[cfg(feature = "unicode_width")]
+        assert_eq!(display_width("日本e\u{301}"), 5);
+    }
 }
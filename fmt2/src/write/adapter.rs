@@ -0,0 +1,188 @@
+use crate::write::Write;
+
+/// Adapter to use any [`core::fmt::Write`] as a [`Write`], such as a `heapless::String`
+///
+/// For a `&mut dyn core::fmt::Write`, [`Write`] is implemented directly on `dyn core::fmt::Write`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FmtWriteAdapter<W>(pub W)
+where
+    W: core::fmt::Write;
+
+impl<W> FmtWriteAdapter<W>
+where
+    W: core::fmt::Write,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: W) -> Self {
+        Self(inner)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+}
+
+impl<W> Write for FmtWriteAdapter<W>
+where
+    W: core::fmt::Write,
+{
+    type Error = core::fmt::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
+        self.0.write_char(c)
+    }
+
+    #[inline]
+    fn write_std_args(&mut self, args: core::fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.0.write_fmt(args)
+    }
+
+    #[inline]
+    fn std_write_adapter(
+        &mut self,
+        f: impl FnOnce(&mut dyn core::fmt::Write) -> core::fmt::Result,
+    ) -> Result<(), Self::Error> {
+        f(&mut self.0)
+    }
+}
+
+impl<W> From<W> for FmtWriteAdapter<W>
+where
+    W: core::fmt::Write,
+{
+    #[inline]
+    fn from(inner: W) -> Self {
+        Self(inner)
+    }
+}
+
+impl Write for dyn core::fmt::Write + '_ {
+    type Error = core::fmt::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        core::fmt::Write::write_str(self, s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
+        core::fmt::Write::write_char(self, c)
+    }
+
+    #[inline]
+    fn write_std_args(&mut self, args: core::fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.write_fmt(args)
+    }
+
+    #[inline]
+    fn std_write_adapter(
+        &mut self,
+        f: impl FnOnce(&mut dyn core::fmt::Write) -> core::fmt::Result,
+    ) -> Result<(), Self::Error> {
+        f(self)
+    }
+}
+
+/// Adapter to use any [`Write`] as a [`core::fmt::Write`], the reverse of [`FmtWriteAdapter`]
+///
+/// [`core::fmt::Error`] can't hold any information, so the error of the writer is kept
+/// in the adapter and can be taken out with [`StdWriteAdapter::into_result`].
+pub struct StdWriteAdapter<'w, W>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+    result: Result<(), W::Error>,
+}
+
+impl<'w, W> StdWriteAdapter<'w, W>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    pub const fn new(writer: &'w mut W) -> Self {
+        Self {
+            writer,
+            result: Ok(()),
+        }
+    }
+
+    /// The last error of the writer, if there was one
+    #[inline]
+    pub fn into_result(self) -> Result<(), W::Error> {
+        self.result
+    }
+
+    #[inline]
+    fn handle(&mut self, result: Result<(), W::Error>) -> core::fmt::Result {
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.result = Err(e);
+                Err(core::fmt::Error)
+            }
+        }
+    }
+}
+
+impl<W> core::fmt::Write for StdWriteAdapter<'_, W>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let result = self.writer.write_str(s);
+        self.handle(result)
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        let result = self.writer.write_char(c);
+        self.handle(result)
+    }
+
+    fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        let result = self.writer.write_std_args(args);
+        self.handle(result)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{FmtWriteAdapter, StdWriteAdapter};
+    use crate::write::array_buf::ArrayBuf;
+
+    #[test]
+    fn both_ways() {
+        let mut w = FmtWriteAdapter::new(String::new());
+        crate::fmt! { (? w) => "a" {1} }.unwrap();
+        let dyn_w: &mut dyn core::fmt::Write = &mut w.0;
+        crate::fmt! { (? dyn_w) => "b" {2} }.unwrap();
+        assert_eq!(w.into_inner(), "a1b2");
+
+        let mut buf = ArrayBuf::<4>::new();
+        let mut adapter = StdWriteAdapter::new(&mut buf);
+        assert!(core::fmt::Write::write_fmt(&mut adapter, format_args!("{}", 12345)).is_err());
+        assert!(adapter.into_result().is_err());
+    }
+}
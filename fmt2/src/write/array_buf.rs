@@ -0,0 +1,270 @@
+use crate::{
+    utils::floor_char_boundary,
+    write::{Flush, Write, WriteInfallible},
+    write_to::WriteTo,
+};
+
+/// Fixed-capacity string buffer on the stack, for targets without an allocator
+///
+/// # Overflow
+/// - `TRUNCATE = false`: a write that doesn't fit fails with a [`CapacityError`] and nothing of it is written.
+///   [`Write::write`] and [`Write::writeln`] roll back everything the [`WriteTo`] wrote before the error,
+///   but separate writes like the parts of [`fmt!`](crate::fmt) are kept
+/// - `TRUNCATE = true`: writing is infallible, the output is cut off on a char boundary
+///   and every write afterwards is ignored, check [`ArrayBuf::is_truncated`]
+#[derive(Clone, Copy)]
+pub struct ArrayBuf<const N: usize, const TRUNCATE: bool = false> {
+    buf: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize, const TRUNCATE: bool> ArrayBuf<N, TRUNCATE> {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // only whole strs or prefixes cut on a char boundary are ever copied into the buffer
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Whether some output was cut off, only possible if `TRUNCATE = true`
+    #[inline]
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Quick check using [`WriteTo::len_hint`] whether `wt` might fit in the remaining space.
+    ///
+    /// The hint is a lower bound, so `false` means it definitely won't fit, `true` means it may fit.
+    #[inline]
+    #[must_use]
+    pub fn may_fit<WT>(&self, wt: &WT) -> bool
+    where
+        WT: WriteTo + ?Sized,
+    {
+        wt.len_hint() <= self.remaining()
+    }
+
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+
+    #[inline]
+    fn push_str_unchecked(&mut self, s: &str) {
+        debug_assert!(s.len() <= self.remaining());
+        let end = self.len + s.len();
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+    }
+}
+
+impl<const N: usize, const TRUNCATE: bool> Default for ArrayBuf<N, TRUNCATE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for ArrayBuf<N, false> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        if s.len() > self.remaining() {
+            return Err(CapacityError {
+                len: s.len(),
+                remaining: self.remaining(),
+            });
+        }
+        self.push_str_unchecked(s);
+        Ok(())
+    }
+
+    /// Rolls back the whole `wt` if a part of it doesn't fit
+    fn write_advanced<WT, const FLUSH: bool, const NEWLINE: bool>(
+        &mut self,
+        wt: &WT,
+    ) -> Result<(), Self::Error>
+    where
+        WT: WriteTo + ?Sized,
+    {
+        let len = self.len;
+        let result = wt.write_to(self).and_then(|()| {
+            if NEWLINE {
+                self.write_str("\n")
+            } else {
+                Ok(())
+            }
+        });
+        if result.is_err() {
+            self.len = len;
+        }
+        result
+    }
+}
+
+impl<const N: usize> Flush for ArrayBuf<N, false> {
+    #[cfg(feature = "never_type")]
+    type Error = !;
+    #[cfg(not(feature = "never_type"))]
+    type Error = ::core::convert::Infallible;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> WriteInfallible for ArrayBuf<N, true> {
+    #[inline]
+    fn write_str_infallible(&mut self, s: &str) {
+        if self.truncated {
+            return;
+        }
+        if s.len() <= self.remaining() {
+            self.push_str_unchecked(s);
+        } else {
+            let end = floor_char_boundary(s, self.remaining());
+            self.push_str_unchecked(unsafe { s.get_unchecked(..end) });
+            self.truncated = true;
+        }
+    }
+}
+
+impl<const N: usize, const TRUNCATE: bool> WriteTo for ArrayBuf<N, TRUNCATE> {
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(self.as_str())
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.len
+    }
+}
+
+impl<const N: usize, const TRUNCATE: bool> AsRef<str> for ArrayBuf<N, TRUNCATE> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const TRUNCATE: bool> core::fmt::Debug for ArrayBuf<N, TRUNCATE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const TRUNCATE: bool> core::fmt::Display for ArrayBuf<N, TRUNCATE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A write to an [`ArrayBuf`] didn't fit in the remaining space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    /// length of the str that didn't fit
+    pub len: usize,
+    /// space that was left in the buffer
+    pub remaining: usize,
+}
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "buffer capacity exceeded: tried to write {} bytes but only {} remaining",
+            self.len, self.remaining
+        )
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArrayBuf, CapacityError};
+    use crate::write::Write;
+
+    #[test]
+    fn overflow() {
+        let mut buf = ArrayBuf::<8>::new();
+        buf.write("abc").unwrap();
+        assert_eq!(
+            buf.write("123456"),
+            Err(CapacityError {
+                len: 6,
+                remaining: 5
+            })
+        );
+        assert_eq!(buf.as_str(), "abc");
+        assert!(buf.may_fit("12345"));
+        assert!(!buf.may_fit("123456"));
+
+        let parts = ("de", "fghij");
+        assert!(buf.write(&parts).is_err());
+        assert_eq!(buf.as_str(), "abc");
+        assert!(buf.writeln("abcde").is_err());
+        assert_eq!(buf.as_str(), "abc");
+    }
+
+    #[test]
+    fn truncate() {
+        let mut buf = ArrayBuf::<4, true>::new();
+        let Ok(()) = buf.write("aé");
+        assert!(!buf.is_truncated());
+        let Ok(()) = buf.write("€");
+        assert_eq!(buf.as_str(), "aé");
+        assert!(buf.is_truncated());
+        let Ok(()) = buf.write("b");
+        assert_eq!(buf.as_str(), "aé");
+    }
+}
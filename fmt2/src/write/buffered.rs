@@ -0,0 +1,287 @@
+use crate::write::{Flush, Write, array_buf::ArrayBuf};
+
+/// Writer that collects the output in a buffer on the stack and writes it to the writer in one go
+///
+/// # Flushing
+/// The buffer is written out and the writer is flushed:
+/// - on [`Write::flush_hint`], which [`fmt!`](crate::fmt) gives at the end of every invocation,
+///   unless a frame was started with [`Buffered::begin_frame`], then only on [`Buffered::end_frame`]
+/// - after a newline if `LINE_BUFFERED = true`
+/// - on [`Flush::flush`] or when it's dropped
+///
+/// The buffer is also written out when it's full, a write that is larger than the buffer goes straight to the writer.
+///
+/// # Errors
+/// When writing the buffer fails, it's kept to be written again later.
+/// An error on a flush hint can't be returned there, so it's returned from the next
+/// [`Write::write_str`], [`Buffered::write_buf`], [`Buffered::end_frame`] or [`Flush::flush`].
+pub struct Buffered<'w, W, const N: usize = 1024, const LINE_BUFFERED: bool = false>
+where
+    W: Write + ?Sized,
+{
+    inner: Inner<'w, W, N>,
+}
+
+/// State of a [`Buffered`] that doesn't depend on `LINE_BUFFERED`, so switching it is a move of this
+struct Inner<'w, W, const N: usize>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+    buf: ArrayBuf<N>,
+    in_frame: bool,
+    /// Error of writing the buffer on a flush hint
+    error: Option<W::Error>,
+}
+
+impl<W, const N: usize> Inner<'_, W, N>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    fn take_error(&mut self) -> Result<(), W::Error> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+
+    fn write_buf(&mut self) -> Result<(), W::Error> {
+        self.take_error()?;
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        self.writer.write_str(self.buf.as_str())?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+impl<W, const N: usize> Drop for Inner<'_, W, N>
+where
+    W: Write + ?Sized,
+{
+    fn drop(&mut self) {
+        if self.write_buf().is_ok() {
+            self.writer.flush_hint();
+        }
+    }
+}
+
+impl<'w, W> Buffered<'w, W>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    pub const fn new(writer: &'w mut W) -> Self {
+        Self::with_capacity(writer)
+    }
+}
+
+impl<'w, W, const N: usize> Buffered<'w, W, N>
+where
+    W: Write + ?Sized,
+{
+    /// Use a buffer of `N` bytes, like `Buffered::<_, 4096>::with_capacity(writer)`
+    #[inline]
+    pub const fn with_capacity(writer: &'w mut W) -> Self {
+        Self {
+            inner: Inner {
+                writer,
+                buf: ArrayBuf::new(),
+                in_frame: false,
+                error: None,
+            },
+        }
+    }
+}
+
+impl<'w, W, const N: usize, const LINE_BUFFERED: bool> Buffered<'w, W, N, LINE_BUFFERED>
+where
+    W: Write + ?Sized,
+{
+    /// Flush after every newline
+    #[inline]
+    pub fn line_buffered(self) -> Buffered<'w, W, N, true> {
+        Buffered { inner: self.inner }
+    }
+
+    /// Only flush on flush hints, frame ends and [`Flush::flush`]
+    #[inline]
+    pub fn block_buffered(self) -> Buffered<'w, W, N, false> {
+        Buffered { inner: self.inner }
+    }
+
+    /// The buffered output that hasn't been written yet
+    #[inline]
+    #[must_use]
+    pub const fn buffer(&self) -> &str {
+        self.inner.buf.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_in_frame(&self) -> bool {
+        self.inner.in_frame
+    }
+
+    /// Ignore flush hints until [`Buffered::end_frame`], so a whole frame (like a prompt redraw) is written in one go
+    #[inline]
+    pub const fn begin_frame(&mut self) {
+        self.inner.in_frame = true;
+    }
+
+    /// Write the frame to the writer and give it a flush hint
+    pub fn end_frame(&mut self) -> Result<(), W::Error> {
+        self.inner.in_frame = false;
+        self.write_buf()?;
+        self.inner.writer.flush_hint();
+        Ok(())
+    }
+
+    /// Write the buffer to the writer without flushing it
+    #[inline]
+    pub fn write_buf(&mut self) -> Result<(), W::Error> {
+        self.inner.write_buf()
+    }
+
+    fn write_unbuffered(&mut self, s: &str) -> Result<(), W::Error> {
+        if self.inner.buf.write_str(s).is_err() {
+            self.write_buf()?;
+            if self.inner.buf.write_str(s).is_err() {
+                self.inner.writer.write_str(s)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W, const N: usize, const LINE_BUFFERED: bool> Write for Buffered<'_, W, N, LINE_BUFFERED>
+where
+    W: Write + ?Sized,
+{
+    type Error = W::Error;
+
+    const IS_LINE_BUFFERED: bool = LINE_BUFFERED;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.inner.take_error()?;
+        if LINE_BUFFERED && let Some(i) = s.rfind('\n') {
+            let (lines, rest) = s.split_at(i + 1);
+            self.write_unbuffered(lines)?;
+            self.write_buf()?;
+            self.inner.writer.flush_hint();
+            self.write_unbuffered(rest)
+        } else {
+            self.write_unbuffered(s)
+        }
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        if !self.inner.in_frame {
+            match self.write_buf() {
+                Ok(()) => self.inner.writer.flush_hint(),
+                Err(e) => self.inner.error = Some(e),
+            }
+        }
+    }
+}
+
+impl<W, const N: usize, const LINE_BUFFERED: bool> Flush for Buffered<'_, W, N, LINE_BUFFERED>
+where
+    W: Write + Flush<Error = <W as Write>::Error> + ?Sized,
+{
+    type Error = <W as Flush>::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.write_buf()?;
+        self.inner.writer.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Buffered;
+    use crate::write::Write;
+
+    /// Records every write to the inner writer
+    #[derive(Default)]
+    struct Syscalls {
+        writes: Vec<String>,
+        flushes: usize,
+    }
+
+    impl Write for Syscalls {
+        type Error = core::convert::Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.writes.push(s.into());
+            Ok(())
+        }
+
+        fn flush_hint(&mut self) {
+            self.flushes += 1;
+        }
+    }
+
+    #[test]
+    fn batches() {
+        let mut inner = Syscalls::default();
+        {
+            let mut w = Buffered::new(&mut inner);
+            crate::fmt! { (w) => "a" {1} "b" {2} "c" };
+            w.begin_frame();
+            crate::fmt! { (w) => "d" };
+            crate::fmt! { (w) => "e" };
+            w.end_frame().unwrap();
+        }
+        assert_eq!(inner.writes, ["a1b2c", "de"]);
+        assert_eq!(inner.flushes, 3);
+
+        let mut inner = Syscalls::default();
+        {
+            let mut w = Buffered::<_, 4>::with_capacity(&mut inner).line_buffered();
+            let Ok(()) = w.write_str("ab\ncd");
+            let Ok(()) = w.write_str("0123456789");
+            assert_eq!(w.buffer(), "");
+        }
+        assert_eq!(inner.writes, ["ab\n", "cd", "0123456789"]);
+        assert_eq!(inner.flushes, 2);
+    }
+
+    /// Fails every write while `fail` is set
+    #[derive(Default)]
+    struct Failing {
+        fail: bool,
+        written: String,
+    }
+
+    impl Write for Failing {
+        type Error = ();
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            if self.fail {
+                return Err(());
+            }
+            self.written.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mut inner = Failing {
+            fail: true,
+            ..Failing::default()
+        };
+        {
+            let mut w = Buffered::new(&mut inner);
+            assert_eq!(crate::fmt! { (? w) => "a" {1} }, Ok(()));
+            assert_eq!(w.buffer(), "a1");
+            assert_eq!(w.write_str("b"), Err(()));
+            assert_eq!(w.write_str("b"), Ok(()));
+            w.inner.writer.fail = false;
+            assert_eq!(w.end_frame(), Ok(()));
+        }
+        assert_eq!(inner.written, "a1b");
+    }
+}
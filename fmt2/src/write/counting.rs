@@ -0,0 +1,45 @@
+use crate::{
+    ansi::{AnsiScanner, AnsiSegment},
+    utils::str_width,
+    write::WriteInfallible,
+};
+
+/// Writer that discards the output and only counts it, to measure a [`WriteTo`](crate::write_to::WriteTo) exactly
+///
+/// See [`WriteTo::measure`](crate::write_to::WriteTo::measure) and [`WriteTo::exact_len`](crate::write_to::WriteTo::exact_len)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CountingWriter {
+    /// length in bytes
+    pub bytes: usize,
+    /// amount of chars
+    pub chars: usize,
+    /// width in terminal columns, ignoring ANSI escape sequences, see [`display_width`](crate::utils::display_width)
+    pub width: usize,
+    scanner: AnsiScanner,
+}
+
+impl CountingWriter {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: 0,
+            chars: 0,
+            width: 0,
+            scanner: AnsiScanner::new(),
+        }
+    }
+}
+
+impl WriteInfallible for CountingWriter {
+    #[inline]
+    fn write_str_infallible(&mut self, s: &str) {
+        self.bytes += s.len();
+        self.chars += s.chars().count();
+        for segment in self.scanner.segments(s) {
+            if let AnsiSegment::Text(text) = segment {
+                self.width += str_width(text);
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::write::{Flush, Write};
+
+/// Object safe version of [`Write`] and [`Flush`] with [`std::io::Error`] as the error, for `Box<dyn DynWrite>`
+///
+/// It's implemented for every [`Write`] and [`Flush`] with [`std::io::Error`] as the error, like [`Stdout`](std::io::Stdout)
+/// or [`IoWriter`](crate::write::io::IoWriter). Other writers can be wrapped in an [`ErasedWriter`].
+/// `dyn DynWrite` and `Box<dyn DynWrite>` implement [`Write`] and [`Flush`], so they can be used with [`fmt!`](crate::fmt)
+/// and the output can be switched at runtime between stdout, a file or a buffer.
+pub trait DynWrite {
+    fn dyn_write_str(&mut self, s: &str) -> std::io::Result<()>;
+
+    fn dyn_flush_hint(&mut self);
+
+    fn dyn_flush(&mut self) -> std::io::Result<()>;
+}
+
+impl<W> DynWrite for W
+where
+    W: Write<Error = std::io::Error> + Flush<Error = std::io::Error> + ?Sized,
+{
+    #[inline]
+    fn dyn_write_str(&mut self, s: &str) -> std::io::Result<()> {
+        self.write_str(s)
+    }
+
+    #[inline]
+    fn dyn_flush_hint(&mut self) {
+        self.flush_hint();
+    }
+
+    #[inline]
+    fn dyn_flush(&mut self) -> std::io::Result<()> {
+        self.flush()
+    }
+}
+
+impl Write for dyn DynWrite + '_ {
+    type Error = std::io::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.dyn_write_str(s)
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.dyn_flush_hint();
+    }
+}
+
+impl Flush for dyn DynWrite + '_ {
+    type Error = std::io::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.dyn_flush()
+    }
+}
+
+// calls go through `**self`, the box itself also implements `DynWrite` with this impl, which would recurse
+impl Write for alloc::boxed::Box<dyn DynWrite + '_> {
+    type Error = std::io::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        (**self).dyn_write_str(s)
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        (**self).dyn_flush_hint();
+    }
+}
+
+impl Flush for alloc::boxed::Box<dyn DynWrite + '_> {
+    type Error = std::io::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        (**self).dyn_flush()
+    }
+}
+
+/// Adapter for any [`Write`] that turns its errors into a [`std::io::Error`], so it can be used as a [`DynWrite`]
+///
+/// Errors are wrapped with [`std::io::Error::other`], unless they already are a [`std::io::Error`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ErasedWriter<W>(pub W)
+where
+    W: Write,
+    W::Error: core::error::Error + Send + Sync + 'static;
+
+impl<W> ErasedWriter<W>
+where
+    W: Write,
+    W::Error: core::error::Error + Send + Sync + 'static,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: W) -> Self {
+        Self(inner)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+}
+
+impl<W> Write for ErasedWriter<W>
+where
+    W: Write,
+    W::Error: core::error::Error + Send + Sync + 'static,
+{
+    type Error = std::io::Error;
+
+    const IS_LINE_BUFFERED: bool = W::IS_LINE_BUFFERED;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_str(s).map_err(into_io_error)
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.0.flush_hint();
+    }
+}
+
+impl<W> Flush for ErasedWriter<W>
+where
+    W: Write + Flush,
+    <W as Write>::Error: core::error::Error + Send + Sync + 'static,
+    <W as Flush>::Error: core::error::Error + Send + Sync + 'static,
+{
+    type Error = std::io::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.0.flush().map_err(into_io_error)
+    }
+}
+
+fn into_io_error<E>(e: E) -> std::io::Error
+where
+    E: core::error::Error + Send + Sync + 'static,
+{
+    let e: alloc::boxed::Box<dyn core::error::Error + Send + Sync> = alloc::boxed::Box::new(e);
+    match e.downcast::<std::io::Error>() {
+        Ok(e) => *e,
+        Err(e) => std::io::Error::other(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynWrite, ErasedWriter};
+    use crate::write::{Flush, Write, array_buf::ArrayBuf, io::IoWriter};
+
+    #[test]
+    fn erased() {
+        let mut v = Vec::new();
+        {
+            let mut outputs: Vec<Box<dyn DynWrite + '_>> = vec![
+                Box::new(ErasedWriter::new(ArrayBuf::<4>::new())),
+                Box::new(IoWriter::new(&mut v)),
+            ];
+            for output in &mut outputs {
+                let _ = crate::fmt! { (? output) => "abc" {12} };
+            }
+            let err = outputs[0].write_str("12345").unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::Other);
+            assert!(
+                err.get_ref()
+                    .unwrap()
+                    .is::<crate::write::array_buf::CapacityError>()
+            );
+        }
+        assert_eq!(v, b"abc12");
+
+        let mut output: Box<dyn DynWrite> = Box::new(IoWriter::new(std::io::BufWriter::new(Vec::new())));
+        crate::fmt! { (? output) => "abc" }.unwrap();
+        output.flush().unwrap();
+    }
+}
@@ -0,0 +1,263 @@
+use core::marker::PhantomData;
+
+use crate::{
+    write::{Flush, Write},
+    write_to::{Fmt, WriteTo},
+};
+
+/// Escaping rules for a context like a JSON string or an HTML page, used by [`Escape`]
+pub trait Escaper {
+    /// Written before the escaped text by the wrappers like [`JsonEscaped`]
+    const PREFIX: &'static str = "";
+    /// Written after the escaped text by the wrappers like [`JsonEscaped`]
+    const SUFFIX: &'static str = "";
+
+    fn needs_escape(c: char) -> bool;
+
+    fn write_escaped<W>(c: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized;
+}
+
+/// Escape for a JSON string
+///
+/// [`Escape`] only escapes, for text that goes between quotes. [`JsonEscaped`] (`{x; json}`) also writes the quotes.
+pub struct Json;
+
+impl Escaper for Json {
+    const PREFIX: &'static str = "\"";
+    const SUFFIX: &'static str = "\"";
+
+    #[inline]
+    fn needs_escape(c: char) -> bool {
+        matches!(c, '"' | '\\' | '\0'..='\x1F')
+    }
+
+    fn write_escaped<W>(c: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match c {
+            '"' => w.write_str("\\\""),
+            '\\' => w.write_str("\\\\"),
+            '\n' => w.write_str("\\n"),
+            '\r' => w.write_str("\\r"),
+            '\t' => w.write_str("\\t"),
+            '\x08' => w.write_str("\\b"),
+            '\x0C' => w.write_str("\\f"),
+            c => {
+                const HEX: &[u8; 16] = b"0123456789abcdef";
+                let c = c as u8;
+                let escape = [
+                    b'\\',
+                    b'u',
+                    b'0',
+                    b'0',
+                    HEX[usize::from(c >> 4)],
+                    HEX[usize::from(c & 0xF)],
+                ];
+                w.write_str(unsafe { core::str::from_utf8_unchecked(&escape) })
+            }
+        }
+    }
+}
+
+/// Escape for HTML text and attribute values
+pub struct Html;
+
+impl Escaper for Html {
+    #[inline]
+    fn needs_escape(c: char) -> bool {
+        matches!(c, '&' | '<' | '>' | '"' | '\'')
+    }
+
+    fn write_escaped<W>(c: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            _ => "&#39;",
+        })
+    }
+}
+
+/// Escape for a single argument in a POSIX shell command line
+///
+/// [`Escape`] only escapes, for text that goes between single quotes. [`ShellEscaped`] (`{x; shell}`) also writes the quotes.
+pub struct Shell;
+
+impl Escaper for Shell {
+    const PREFIX: &'static str = "'";
+    const SUFFIX: &'static str = "'";
+
+    #[inline]
+    fn needs_escape(c: char) -> bool {
+        c == '\''
+    }
+
+    #[inline]
+    fn write_escaped<W>(_: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str("'\\''")
+    }
+}
+
+/// Escape for a CSV cell (RFC 4180)
+///
+/// [`Escape`] only escapes, for text that goes between double quotes. [`CsvEscaped`] (`{x; csv}`) also writes the quotes.
+pub struct Csv;
+
+impl Escaper for Csv {
+    const PREFIX: &'static str = "\"";
+    const SUFFIX: &'static str = "\"";
+
+    #[inline]
+    fn needs_escape(c: char) -> bool {
+        c == '"'
+    }
+
+    #[inline]
+    fn write_escaped<W>(_: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str("\"\"")
+    }
+}
+
+/// Writer that escapes everything written to it with the rules of `E`
+///
+/// Only the text is escaped, [`Escaper::PREFIX`] and [`Escaper::SUFFIX`] aren't written,
+/// the wrappers like [`JsonEscaped`] do that.
+pub struct Escape<'w, W, E>
+where
+    W: Write + ?Sized,
+    E: Escaper,
+{
+    writer: &'w mut W,
+    escaper: PhantomData<E>,
+}
+
+impl<'w, W, E> Escape<'w, W, E>
+where
+    W: Write + ?Sized,
+    E: Escaper,
+{
+    #[inline]
+    pub const fn new(writer: &'w mut W) -> Self {
+        Self {
+            writer,
+            escaper: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> &'w mut W {
+        self.writer
+    }
+}
+
+impl<W, E> Write for Escape<'_, W, E>
+where
+    W: Write + ?Sized,
+    E: Escaper,
+{
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            if E::needs_escape(c) {
+                self.writer
+                    .write_str(unsafe { s.get_unchecked(start..i) })?;
+                E::write_escaped(c, self.writer)?;
+                start = i + c.len_utf8();
+            }
+        }
+        self.writer.write_str(unsafe { s.get_unchecked(start..) })
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.writer.flush_hint();
+    }
+}
+
+impl<W, E> Flush for Escape<'_, W, E>
+where
+    W: Write + Flush + ?Sized,
+    E: Escaper,
+{
+    type Error = <W as Flush>::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+macro_rules! declare_escaped_wrapper_internal {
+    ($($Struct:ident $Trait:ident $fn:ident => $Escaper:ident),* $(,)?) => {
+        $(
+            crate::declare_fmt_wrapper_struct! { $Struct $Trait $fn }
+
+            impl<T> WriteTo for $Struct<T>
+            where
+                T: Fmt + ?Sized,
+            {
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    w.write_str(<$Escaper as Escaper>::PREFIX)?;
+                    self.0.fmt().write_to(&mut Escape::<W, $Escaper>::new(w))?;
+                    w.write_str(<$Escaper as Escaper>::SUFFIX)
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    <$Escaper as Escaper>::PREFIX.len()
+                        + self.0.fmt().len_hint()
+                        + <$Escaper as Escaper>::SUFFIX.len()
+                }
+            }
+        )*
+    };
+}
+
+declare_escaped_wrapper_internal! {
+    JsonEscaped     FmtJson     fmt_json    => Json,
+    HtmlEscaped     FmtHtml     fmt_html    => Html,
+    ShellEscaped    FmtShell    fmt_shell   => Shell,
+    CsvEscaped      FmtCsv      fmt_csv     => Csv,
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    #[test]
+    fn escape() {
+        let name = "Bob \"the\" <builder>\n";
+        let s = crate::fmt! { { str } => "{\"name\":" {name; json} "}" };
+        assert_eq!(s, r#"{"name":"Bob \"the\" <builder>\n"}"#);
+        let s = crate::fmt! { { str } => "<b>" {name; html} "</b>" };
+        assert_eq!(s, "<b>Bob &quot;the&quot; &lt;builder&gt;\n</b>");
+
+        let file = "it's $HOME";
+        let s = crate::fmt! { { str } => "rm " {file; shell} };
+        assert_eq!(s, r"rm 'it'\''s $HOME'");
+
+        let cell = "a,\"b\"";
+        let n = 5;
+        let s = crate::fmt! { { str } => {cell; csv} "," {n; csv} };
+        assert_eq!(s, r#""a,""b""","5""#);
+
+        let s = crate::fmt! { { str } => {"\x1B"; json} };
+        assert_eq!(s, r#""\u001b""#);
+    }
+}
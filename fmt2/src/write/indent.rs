@@ -0,0 +1,137 @@
+use crate::{
+    write::{Flush, Write},
+    write_to::WriteTo,
+};
+
+/// Writer that writes a prefix at the start of every line
+///
+/// The prefix is written lazily before the first char of a line, so it doesn't matter how the output
+/// is split into separate writes. Empty lines get no prefix, so they don't end in trailing whitespace.
+/// Indentation levels compose by wrapping an [`Indent`] in another [`Indent`].
+pub struct Indent<'w, 'p, W, P = str>
+where
+    W: Write + ?Sized,
+    P: WriteTo + ?Sized,
+{
+    writer: &'w mut W,
+    prefix: &'p P,
+    at_line_start: bool,
+}
+
+impl<'w, 'p, W, P> Indent<'w, 'p, W, P>
+where
+    W: Write + ?Sized,
+    P: WriteTo + ?Sized,
+{
+    /// The first line is also indented, see [`Indent::new_mid_line`] if the writer isn't at the start of a line
+    #[inline]
+    pub const fn new(writer: &'w mut W, prefix: &'p P) -> Self {
+        Self {
+            writer,
+            prefix,
+            at_line_start: true,
+        }
+    }
+
+    /// Only indent from the next line onwards
+    #[inline]
+    pub const fn new_mid_line(writer: &'w mut W, prefix: &'p P) -> Self {
+        Self {
+            writer,
+            prefix,
+            at_line_start: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_at_line_start(&self) -> bool {
+        self.at_line_start
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> &'w mut W {
+        self.writer
+    }
+}
+
+impl<W, P> Write for Indent<'_, '_, W, P>
+where
+    W: Write + ?Sized,
+    P: WriteTo + ?Sized,
+{
+    type Error = W::Error;
+
+    const IS_LINE_BUFFERED: bool = W::IS_LINE_BUFFERED;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut rest = s;
+        while !rest.is_empty() {
+            if self.at_line_start && !rest.starts_with('\n') && !rest.starts_with("\r\n") {
+                self.prefix.write_to(self.writer)?;
+                self.at_line_start = false;
+            }
+            match rest.find('\n') {
+                Some(i) => {
+                    let (line, next) = rest.split_at(i + 1);
+                    self.writer.write_str(line)?;
+                    self.at_line_start = true;
+                    rest = next;
+                }
+                None => {
+                    return self.writer.write_str(rest);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.writer.flush_hint();
+    }
+}
+
+impl<W, P> Flush for Indent<'_, '_, W, P>
+where
+    W: Write + Flush + ?Sized,
+    P: WriteTo + ?Sized,
+{
+    type Error = <W as Flush>::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Indent;
+    use crate::write::Write;
+
+    #[test]
+    fn nested() {
+        let mut s = String::new();
+        let mut outer = Indent::new(&mut s, "  ");
+        let Ok(()) = outer.writeln("a");
+        {
+            let mut inner = Indent::new(&mut outer, "│ ");
+            let Ok(()) = inner.write_str("b\nc");
+            let Ok(()) = inner.write_str("d\n\ne");
+            let Ok(()) = inner.writeln("");
+        }
+        let Ok(()) = outer.write_str("f");
+        assert_eq!(s, "  a\n  │ b\n  │ cd\n\n  │ e\n  f");
+    }
+
+    #[test]
+    fn empty_lines() {
+        let mut s = String::new();
+        let mut w = Indent::new(&mut s, "    ");
+        let Ok(()) = w.write_str("a\n\n");
+        let Ok(()) = w.write_str("\r\nb\n");
+        let Ok(()) = w.write_str("\n");
+        assert_eq!(s, "    a\n\n\r\n    b\n\n");
+    }
+}
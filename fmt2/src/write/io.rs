@@ -0,0 +1,127 @@
+use crate::write::{Flush, Write};
+
+/// Adapter to use any [`std::io::Write`] as a [`Write`], such as a [`File`](std::fs::File),
+/// [`BufWriter`](std::io::BufWriter), [`Vec<u8>`], [`TcpStream`](std::net::TcpStream) or [`ChildStdin`](std::process::ChildStdin)
+///
+/// It also works with references, so `IoWriter<&mut File>` doesn't take ownership of the file.
+///
+/// Flush hints are ignored, so a wrapped [`BufWriter`](std::io::BufWriter) keeps its buffer,
+/// the writer is only flushed with [`Flush::flush`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IoWriter<T>(pub T)
+where
+    T: std::io::Write;
+
+impl<T> IoWriter<T>
+where
+    T: std::io::Write,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Write for IoWriter<T>
+where
+    T: std::io::Write,
+{
+    type Error = std::io::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_all(s.as_bytes())
+    }
+}
+
+impl<T> Flush for IoWriter<T>
+where
+    T: std::io::Write,
+{
+    type Error = std::io::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.0.flush()
+    }
+}
+
+impl<T> std::io::Write for IoWriter<T>
+where
+    T: std::io::Write,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<T> From<T> for IoWriter<T>
+where
+    T: std::io::Write,
+{
+    #[inline]
+    fn from(inner: T) -> Self {
+        Self(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoWriter;
+    use crate::write::{Flush, Write};
+
+    #[test]
+    fn write_to_vec() {
+        let mut w = IoWriter::new(Vec::new());
+        w.write_str("abc").unwrap();
+        w.writeln("123").unwrap();
+        assert_eq!(w.into_inner(), b"abc123\n");
+
+        let mut v = Vec::new();
+        IoWriter::new(&mut v).write_str("by ref").unwrap();
+        assert_eq!(v, b"by ref");
+    }
+
+    #[test]
+    fn fmt_io() {
+        let n = 42;
+        let mut v = Vec::new();
+        crate::fmt! { (? #io &mut v) => "n=" {n} }.unwrap();
+        crate::fmt! { (#io &mut v) => " done" };
+        assert_eq!(v, b"n=42 done");
+    }
+
+    #[test]
+    fn flush() {
+        let mut w = IoWriter::new(std::io::BufWriter::new(Vec::new()));
+        crate::fmt! { (? w) => "abc" }.unwrap();
+        assert_eq!(w.get_ref().get_ref(), b"");
+        w.flush().unwrap();
+        assert_eq!(w.get_ref().get_ref(), b"abc");
+    }
+}
@@ -1,8 +1,28 @@
 use crate::write_to::WriteTo;
 
+pub mod adapter;
+pub mod array_buf;
+pub mod buffered;
+pub mod counting;
+#[cfg(feature = "std")]
+pub mod dyn_write;
+pub mod escape;
+pub mod indent;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod sanitize;
+pub mod strip_ansi;
+pub mod tee;
+pub mod truncate;
+#[cfg(feature = "alloc")]
+pub mod wrap;
+
 pub trait Write {
     type Error;
 
+    /// The writer flushes on its own after a newline, so a flush hint after output that ends in a newline is skipped
+    const IS_LINE_BUFFERED: bool = false;
+
     fn write_str(&mut self, s: &str) -> Result<(), Self::Error>;
 
     #[inline]
@@ -25,7 +45,7 @@ pub trait Write {
             // self.write_newline()?;
             self.write_str("\n")?;
         }
-        if FLUSH {
+        if FLUSH && !(Self::IS_LINE_BUFFERED && (NEWLINE || WT::ENDS_IN_NEWLINE)) {
             self.flush_hint();
         }
         Ok(())
@@ -60,6 +80,14 @@ pub trait Write {
     #[inline]
     fn flush_hint(&mut self) {}
 
+    /// Flush hint at the end of some output, see [`Write::IS_LINE_BUFFERED`]
+    #[inline]
+    fn flush_hint_advanced<const FLUSH: bool, const ENDS_IN_NEWLINE: bool>(&mut self) {
+        if FLUSH && !(Self::IS_LINE_BUFFERED && ENDS_IN_NEWLINE) {
+            self.flush_hint();
+        }
+    }
+
     #[inline]
     fn write_std_display<D>(&mut self, d: &D) -> Result<(), Self::Error>
     where
@@ -169,60 +197,14 @@ pub trait Write {
         &mut self,
         f: impl FnOnce(&mut dyn core::fmt::Write) -> core::fmt::Result,
     ) -> Result<(), Self::Error> {
-        struct Adapter<'w, W>
-        where
-            W: Write + ?Sized,
-        {
-            writer: &'w mut W,
-            result: Result<(), W::Error>,
-        }
-
-        impl<W> core::fmt::Write for Adapter<'_, W>
-        where
-            W: Write + ?Sized,
-        {
-            #[inline]
-            fn write_str(&mut self, s: &str) -> core::fmt::Result {
-                match self.writer.write_str(s) {
-                    Ok(()) => Ok(()),
-                    Err(e) => {
-                        self.result = Err(e);
-                        Err(core::fmt::Error)
-                    }
-                }
-            }
-
-            fn write_char(&mut self, c: char) -> core::fmt::Result {
-                match self.writer.write_char(c) {
-                    Ok(()) => Ok(()),
-                    Err(e) => {
-                        self.result = Err(e);
-                        Err(core::fmt::Error)
-                    }
-                }
-            }
-
-            fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
-                match self.writer.write_std_args(args) {
-                    Ok(()) => Ok(()),
-                    Err(e) => {
-                        self.result = Err(e);
-                        Err(core::fmt::Error)
-                    }
-                }
-            }
-        }
-
-        let mut write = Adapter {
-            writer: self,
-            result: Ok(()),
-        };
+        let mut write = adapter::StdWriteAdapter::new(self);
         if f(&mut write).is_ok() {
             Ok(())
         } else {
+            let result = write.into_result();
             // it's possible for Display to error on its own, but it should be write doing the err
-            debug_assert!(write.result.is_err());
-            write.result
+            debug_assert!(result.is_err());
+            result
         }
     }
 
@@ -310,6 +292,22 @@ impl Write for core::fmt::Formatter<'_> {
     }
 }
 
+/// Writers that keep the output in memory have nothing to flush
+impl<W> Flush for W
+where
+    W: WriteInfallible + ?Sized,
+{
+    #[cfg(feature = "never_type")]
+    type Error = !;
+    #[cfg(not(feature = "never_type"))]
+    type Error = ::core::convert::Infallible;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
 // impl Write for String {
 // 	type Error = !;
 //
@@ -331,14 +329,22 @@ pub trait WriteInfallible {
     // }
 }
 
-#[cfg(feature = "std")]
-impl WriteInfallible for String {
+#[cfg(feature = "alloc")]
+impl WriteInfallible for alloc::string::String {
     #[inline]
     fn write_str_infallible(&mut self, s: &str) {
         self.push_str(s);
     }
 }
 
+#[cfg(feature = "alloc")]
+impl WriteInfallible for alloc::vec::Vec<u8> {
+    #[inline]
+    fn write_str_infallible(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes());
+    }
+}
+
 // impl<W> WriteInfallible for W
 // where
 // 	W: Write<Error = !> + ?Sized,
@@ -369,10 +375,18 @@ pub trait Flush {
 #[macro_export]
 macro_rules! impl_write_flush_for_io_write {
 	($($ty:ty),* $(,)?) => {
+		$crate::impl_write_flush_for_io_write! { @impl false => $($ty),* }
+	};
+	(line_buffered: $($ty:ty),* $(,)?) => {
+		$crate::impl_write_flush_for_io_write! { @impl true => $($ty),* }
+	};
+	(@impl $line_buffered:literal => $($ty:ty),*) => {
 		$(
 			impl $crate::write::Write for $ty {
 				type Error = ::std::io::Error;
 
+				const IS_LINE_BUFFERED: bool = $line_buffered;
+
 				#[inline]
 				fn write_str(&mut self, s: &str) -> ::core::result::Result<(), Self::Error> {
 					::std::io::Write::write_all(self, str::as_bytes(s))
@@ -396,10 +410,17 @@ macro_rules! impl_write_flush_for_io_write {
 	};
 }
 
+// stdout is line buffered
 #[cfg(feature = "std")]
 impl_write_flush_for_io_write! {
+    line_buffered:
     std::io::Stdout,
-    std::io::StdoutLock<'_>,
+    std::io::StdoutLock<'_>
+}
+
+// stderr isn't buffered at all
+#[cfg(feature = "std")]
+impl_write_flush_for_io_write! {
     std::io::Stderr,
     std::io::StderrLock<'_>
 }
@@ -0,0 +1,196 @@
+use crate::{
+    write::{Flush, Write},
+    write_to::{Fmt, WriteTo},
+};
+
+/// Writer that makes untrusted text safe to print to a terminal, by replacing control chars with visible escapes
+///
+/// C0 control chars (except `\n` and `\t`), DEL and C1 control chars are replaced, for example `ESC` with `\x1b`.
+/// This neutralises escape sequences, carriage returns and other cursor movement, which could rewrite the screen
+/// and break the line counting of a `ScreenArea`.
+///
+/// If `CONTROL_PICTURES = true`, C0 control chars and DEL are replaced with their control picture instead, like `␛`.
+pub struct Sanitize<'w, W, const CONTROL_PICTURES: bool = false>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+}
+
+impl<'w, W> Sanitize<'w, W>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    pub const fn new(writer: &'w mut W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<'w, W, const CONTROL_PICTURES: bool> Sanitize<'w, W, CONTROL_PICTURES>
+where
+    W: Write + ?Sized,
+{
+    /// Replace C0 control chars and DEL with control pictures like `␛` instead of escapes like `\x1b`
+    #[inline]
+    pub fn control_pictures(self) -> Sanitize<'w, W, true> {
+        Sanitize {
+            writer: self.writer,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> &'w mut W {
+        self.writer
+    }
+
+    fn write_escaped(&mut self, c: char) -> Result<(), W::Error> {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let n = c as u32;
+        if CONTROL_PICTURES && n < 0x80 {
+            let picture = if n == 0x7F {
+                '\u{2421}'
+            } else {
+                unsafe { char::from_u32_unchecked(0x2400 + n) }
+            };
+            return self.writer.write_char(picture);
+        }
+        // C1 control chars are written as a char escape, so they can't be mistaken for a byte of UTF-8
+        let escape: &[u8] = if n < 0x80 {
+            &[b'\\', b'x', HEX[(n >> 4) as usize], HEX[(n & 0xF) as usize]]
+        } else {
+            &[
+                b'\\',
+                b'u',
+                b'{',
+                HEX[(n >> 4) as usize],
+                HEX[(n & 0xF) as usize],
+                b'}',
+            ]
+        };
+        self.writer
+            .write_str(unsafe { core::str::from_utf8_unchecked(escape) })
+    }
+}
+
+/// Whether [`Sanitize`] replaces the char
+#[inline]
+#[must_use]
+pub const fn is_unsafe_control(c: char) -> bool {
+    matches!(c, '\0'..='\x08' | '\x0B'..='\x1F' | '\x7F'..='\u{9F}')
+}
+
+impl<W, const CONTROL_PICTURES: bool> Write for Sanitize<'_, W, CONTROL_PICTURES>
+where
+    W: Write + ?Sized,
+{
+    type Error = W::Error;
+
+    const IS_LINE_BUFFERED: bool = W::IS_LINE_BUFFERED;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            if is_unsafe_control(c) {
+                self.writer
+                    .write_str(unsafe { s.get_unchecked(start..i) })?;
+                self.write_escaped(c)?;
+                start = i + c.len_utf8();
+            }
+        }
+        self.writer.write_str(unsafe { s.get_unchecked(start..) })
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.writer.flush_hint();
+    }
+}
+
+impl<W, const CONTROL_PICTURES: bool> Flush for Sanitize<'_, W, CONTROL_PICTURES>
+where
+    W: Write + Flush + ?Sized,
+{
+    type Error = <W as Flush>::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+crate::declare_fmt_wrapper_struct! {
+    Sanitized FmtSanitized fmt_sanitized,
+    SanitizedPictures FmtSanitizedPictures fmt_sanitized_pictures,
+    SanitizedIf<const SANITIZE: bool> FmtSanitizedIf fmt_sanitized_if,
+}
+
+impl<T> WriteTo for Sanitized<T>
+where
+    T: Fmt + ?Sized,
+{
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.0.fmt().write_to(&mut Sanitize::new(w))
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.fmt().len_hint()
+    }
+}
+
+impl<T> WriteTo for SanitizedPictures<T>
+where
+    T: Fmt + ?Sized,
+{
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.0
+            .fmt()
+            .write_to(&mut Sanitize::new(w).control_pictures())
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.fmt().len_hint()
+    }
+}
+
+/// [`Sanitized`] if `SANITIZE = true`, otherwise written as it is, for options like in the prompts
+impl<T, const SANITIZE: bool> WriteTo for SanitizedIf<T, SANITIZE>
+where
+    T: Fmt + ?Sized,
+{
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        if SANITIZE {
+            self.0.fmt().write_to(&mut Sanitize::new(w))
+        } else {
+            self.0.fmt().write_to(w)
+        }
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.fmt().len_hint()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    #[test]
+    fn sanitize() {
+        let evil = "name\r\x1B[2Aoops\x1B]0;title\x07\u{9B}\tok\n";
+        let s = crate::fmt! { { str } => {evil; safe} };
+        assert_eq!(s, "name\\x0d\\x1b[2Aoops\\x1b]0;title\\x07\\u{9b}\tok\n");
+        let s = crate::fmt! { { str } => {evil; safe pictures} };
+        assert_eq!(s, "name␍␛[2Aoops␛]0;title␇\\u{9b}\tok\n");
+    }
+}
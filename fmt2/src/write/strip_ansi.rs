@@ -0,0 +1,130 @@
+use crate::{
+    ansi::{AnsiScanner, AnsiSegment},
+    write::{Flush, Write},
+};
+
+/// Writer that removes ANSI escape sequences (CSI, OSC, ...) such as colours, cursor movement and clearing
+///
+/// Use [`StripAnsi::auto`] to only strip them if the output isn't a terminal or colours are disabled,
+/// see [`supports_color`](crate::ansi::supports_color).
+pub struct StripAnsi<'w, W>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+    enabled: bool,
+    scanner: AnsiScanner,
+}
+
+impl<'w, W> StripAnsi<'w, W>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    pub const fn new(writer: &'w mut W) -> Self {
+        Self::new_enabled(writer, true)
+    }
+
+    /// Only strip escape sequences if `enabled`, otherwise write everything as it is
+    #[inline]
+    pub const fn new_enabled(writer: &'w mut W, enabled: bool) -> Self {
+        Self {
+            writer,
+            enabled,
+            scanner: AnsiScanner::new(),
+        }
+    }
+
+    /// Strip escape sequences if `writer` isn't a terminal or colours are disabled with `NO_COLOR`,
+    /// see [`supports_color`](crate::ansi::supports_color)
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn auto(writer: &'w mut W) -> Self
+    where
+        W: std::io::IsTerminal,
+    {
+        let enabled = !crate::ansi::supports_color(writer);
+        Self::new_enabled(writer, enabled)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> &'w mut W {
+        self.writer
+    }
+}
+
+impl<W> Write for StripAnsi<'_, W>
+where
+    W: Write + ?Sized,
+{
+    type Error = W::Error;
+
+    const IS_LINE_BUFFERED: bool = W::IS_LINE_BUFFERED;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        if !self.enabled {
+            return self.writer.write_str(s);
+        }
+        for segment in self.scanner.segments(s) {
+            if let AnsiSegment::Text(text) = segment {
+                self.writer.write_str(text)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.writer.flush_hint();
+    }
+}
+
+impl<W> Flush for StripAnsi<'_, W>
+where
+    W: Write + Flush + ?Sized,
+{
+    type Error = <W as Flush>::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::StripAnsi;
+    use crate::write::Write;
+
+    #[test]
+    fn strip() {
+        let mut s = String::new();
+        let mut w = StripAnsi::new(&mut s);
+        let Ok(()) = w.write_str(
+            "\x1B[38;5;9mred\x1B[39m \x1B]8;;https://example.com\x1B\\link\x1B]8;;\x1B\\",
+        );
+        let Ok(()) = w.write_str("\x1B[2K\x1B[1G");
+        assert_eq!(s, "red link");
+
+        let mut s = String::new();
+        let mut w = StripAnsi::new_enabled(&mut s, false);
+        let Ok(()) = w.write_str("\x1B[38;5;9mred\x1B[39m");
+        assert_eq!(s, "\x1B[38;5;9mred\x1B[39m");
+    }
+
+    #[test]
+    fn fmt_auto() {
+        // nothing is written, this only checks that the writer lives long enough for every arm
+        let empty = "";
+        crate::fmt! { (? #auto) => {empty} }.unwrap();
+        crate::fmt! { (#auto) => {empty} };
+        crate::fmt! { (? #err auto) => {empty} }.unwrap();
+        crate::fmt! { (#err auto) => {empty} };
+    }
+}
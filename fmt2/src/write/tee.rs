@@ -0,0 +1,214 @@
+use crate::{
+    ansi::{AnsiScanner, AnsiSegment},
+    write::{Flush, Write},
+};
+
+/// Writer that duplicates the output into two writers
+///
+/// # Errors
+/// - `BEST_EFFORT = false`: stop at the first error, if `a` fails then `b` isn't written to
+/// - `BEST_EFFORT = true`: always write to both, errors of both writers are combined
+///
+/// # Stripping ANSI
+/// If `STRIP_ANSI_B = true`, ANSI escape sequences are removed from the output for `b`.
+/// This way the terminal gets colours and a log file stays clean from the same [`fmt!`](crate::fmt) call.
+pub struct Tee<'a, 'b, A, B, const BEST_EFFORT: bool = false, const STRIP_ANSI_B: bool = false>
+where
+    A: Write + ?Sized,
+    B: Write + ?Sized,
+{
+    a: &'a mut A,
+    b: &'b mut B,
+    scanner: AnsiScanner,
+}
+
+impl<'a, 'b, A, B> Tee<'a, 'b, A, B>
+where
+    A: Write + ?Sized,
+    B: Write + ?Sized,
+{
+    #[inline]
+    pub const fn new(a: &'a mut A, b: &'b mut B) -> Self {
+        Self {
+            a,
+            b,
+            scanner: AnsiScanner::new(),
+        }
+    }
+}
+
+impl<'a, 'b, A, B, const BEST_EFFORT: bool, const STRIP_ANSI_B: bool>
+    Tee<'a, 'b, A, B, BEST_EFFORT, STRIP_ANSI_B>
+where
+    A: Write + ?Sized,
+    B: Write + ?Sized,
+{
+    /// Always write to both writers, even if one of them fails
+    #[inline]
+    pub fn best_effort(self) -> Tee<'a, 'b, A, B, true, STRIP_ANSI_B> {
+        Tee {
+            a: self.a,
+            b: self.b,
+            scanner: self.scanner,
+        }
+    }
+
+    /// Stop at the first error
+    #[inline]
+    pub fn fail_fast(self) -> Tee<'a, 'b, A, B, false, STRIP_ANSI_B> {
+        Tee {
+            a: self.a,
+            b: self.b,
+            scanner: self.scanner,
+        }
+    }
+
+    /// Remove ANSI escape sequences from the output for `b`
+    #[inline]
+    pub fn strip_ansi_b(self) -> Tee<'a, 'b, A, B, BEST_EFFORT, true> {
+        Tee {
+            a: self.a,
+            b: self.b,
+            scanner: self.scanner,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> (&'a mut A, &'b mut B) {
+        (self.a, self.b)
+    }
+
+    #[inline]
+    fn write_str_b(&mut self, s: &str) -> Result<(), B::Error> {
+        if STRIP_ANSI_B {
+            for segment in self.scanner.segments(s) {
+                if let AnsiSegment::Text(text) = segment {
+                    self.b.write_str(text)?;
+                }
+            }
+            Ok(())
+        } else {
+            self.b.write_str(s)
+        }
+    }
+}
+
+impl<A, B, const BEST_EFFORT: bool, const STRIP_ANSI_B: bool> Write
+    for Tee<'_, '_, A, B, BEST_EFFORT, STRIP_ANSI_B>
+where
+    A: Write + ?Sized,
+    B: Write + ?Sized,
+{
+    type Error = TeeError<A::Error, B::Error>;
+
+    const IS_LINE_BUFFERED: bool = A::IS_LINE_BUFFERED && B::IS_LINE_BUFFERED;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        if BEST_EFFORT {
+            let a = self.a.write_str(s);
+            let b = self.write_str_b(s);
+            TeeError::combine(a, b)
+        } else {
+            self.a.write_str(s).map_err(TeeError::A)?;
+            self.write_str_b(s).map_err(TeeError::B)
+        }
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.a.flush_hint();
+        self.b.flush_hint();
+    }
+}
+
+impl<A, B, const BEST_EFFORT: bool, const STRIP_ANSI_B: bool> Flush
+    for Tee<'_, '_, A, B, BEST_EFFORT, STRIP_ANSI_B>
+where
+    A: Write + Flush + ?Sized,
+    B: Write + Flush + ?Sized,
+{
+    type Error = TeeError<<A as Flush>::Error, <B as Flush>::Error>;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        if BEST_EFFORT {
+            let a = self.a.flush();
+            let b = self.b.flush();
+            TeeError::combine(a, b)
+        } else {
+            self.a.flush().map_err(TeeError::A)?;
+            self.b.flush().map_err(TeeError::B)
+        }
+    }
+}
+
+/// Error of a [`Tee`], which writer failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TeeError<EA, EB> {
+    A(EA),
+    B(EB),
+    Both(EA, EB),
+}
+
+impl<EA, EB> TeeError<EA, EB> {
+    #[inline]
+    pub fn combine(a: Result<(), EA>, b: Result<(), EB>) -> Result<(), Self> {
+        match (a, b) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(a), Ok(())) => Err(Self::A(a)),
+            (Ok(()), Err(b)) => Err(Self::B(b)),
+            (Err(a), Err(b)) => Err(Self::Both(a, b)),
+        }
+    }
+}
+
+impl<EA, EB> core::fmt::Display for TeeError<EA, EB>
+where
+    EA: core::fmt::Display,
+    EB: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::A(a) => write!(f, "first writer failed: {a}"),
+            Self::B(b) => write!(f, "second writer failed: {b}"),
+            Self::Both(a, b) => write!(f, "both writers failed: {a}; {b}"),
+        }
+    }
+}
+
+impl<EA, EB> core::error::Error for TeeError<EA, EB>
+where
+    EA: core::error::Error,
+    EB: core::error::Error,
+{
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Tee, TeeError};
+    use crate::write::{Write, array_buf::ArrayBuf};
+
+    #[test]
+    fn strip_ansi_b() {
+        let mut terminal = String::new();
+        let mut log = String::new();
+        let mut tee = Tee::new(&mut terminal, &mut log).strip_ansi_b();
+        tee.write_str(crate::ansi_set_style!(foreground red)).unwrap();
+        tee.write_str("error\x1B[3").unwrap();
+        tee.write_str("9m: oh no").unwrap();
+        assert_eq!(terminal, "\x1B[38;5;9merror\x1B[39m: oh no");
+        assert_eq!(log, "error: oh no");
+    }
+
+    #[test]
+    fn errors() {
+        let mut a = ArrayBuf::<4>::new();
+        let mut b = String::new();
+        let mut tee = Tee::new(&mut a, &mut b);
+        assert!(matches!(tee.write_str("12345"), Err(TeeError::A(_))));
+        let mut tee = tee.best_effort();
+        assert!(matches!(tee.write_str("12345"), Err(TeeError::A(_))));
+        assert_eq!(b, "12345");
+    }
+}
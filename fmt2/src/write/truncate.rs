@@ -0,0 +1,326 @@
+use crate::{
+    ansi::{AnsiScanner, AnsiSegment},
+    utils::char_width,
+    write::{Flush, Write, array_buf::ArrayBuf},
+    write_to::{Fmt, WriteTo},
+};
+
+/// Default ellipsis of a [`Truncate`]
+pub const ELLIPSIS: &str = "…";
+
+/// Writer that stops after `max_width` display columns and ends the output with an ellipsis
+///
+/// If the text doesn't fit, it is cut off so the text and the ellipsis together are at most `max_width` columns wide.
+/// Chars are never split, zero-width chars (like combining marks) and chars joined by a zero-width joiner
+/// are kept together with the char before them.
+/// ANSI escape sequences have no width and are still written after truncating, so a closing style reset isn't lost.
+///
+/// Whether the text fits is only known at the end, so the last few columns are held back until
+/// more text arrives, [`Truncate::finish`] is called or it's dropped.
+/// At most 64 bytes are held back, that is the text under the ellipsis and the escape sequences in it.
+/// If more is held back, like with a long ellipsis, it's written early and the output can go over `max_width`.
+pub struct Truncate<'w, 'e, W, E = str>
+where
+    W: Write + ?Sized,
+    E: WriteTo + ?Sized,
+{
+    writer: &'w mut W,
+    max_width: usize,
+    ellipsis: &'e E,
+    ellipsis_width: usize,
+    /// only updated after the writer wrote what it describes
+    state: State,
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    width: usize,
+    /// text in the last columns that would be replaced by the ellipsis
+    pending: ArrayBuf<64>,
+    pending_width: usize,
+    /// the last char was a zero-width joiner
+    joined: bool,
+    truncated: bool,
+    scanner: AnsiScanner,
+}
+
+impl<'w, W> Truncate<'w, 'static, W>
+where
+    W: Write + ?Sized,
+{
+    /// Truncate with the default [`ELLIPSIS`]
+    #[inline]
+    pub fn new(writer: &'w mut W, max_width: usize) -> Self {
+        Truncate::with_ellipsis(writer, max_width, ELLIPSIS)
+    }
+}
+
+impl<'w, 'e, W, E> Truncate<'w, 'e, W, E>
+where
+    W: Write + ?Sized,
+    E: WriteTo + ?Sized,
+{
+    #[inline]
+    pub fn with_ellipsis(writer: &'w mut W, max_width: usize, ellipsis: &'e E) -> Self {
+        Self {
+            writer,
+            max_width,
+            ellipsis,
+            ellipsis_width: ellipsis.measure().width,
+            state: State {
+                width: 0,
+                pending: ArrayBuf::new(),
+                pending_width: 0,
+                joined: false,
+                truncated: false,
+                scanner: AnsiScanner::new(),
+            },
+        }
+    }
+
+    /// Whether the text was cut off and the ellipsis was written
+    #[inline]
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.state.truncated
+    }
+
+    /// Width of the output so far in display columns, including the ellipsis
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        if self.state.truncated {
+            self.state.width + self.ellipsis_width
+        } else {
+            self.state.width + self.state.pending_width
+        }
+    }
+
+    /// Write the text that was held back, call this when there is no more text
+    pub fn finish(&mut self) -> Result<(), W::Error> {
+        let mut next = self.state;
+        self.commit_pending(&mut next)
+    }
+
+    /// Write `s` and take over `next`, which has to describe the output up to the end of `s`
+    #[inline]
+    fn write_inner(&mut self, s: &str, next: &State) -> Result<(), W::Error> {
+        self.writer.write_str(s)?;
+        self.state = *next;
+        Ok(())
+    }
+
+    fn commit_pending(&mut self, next: &mut State) -> Result<(), W::Error> {
+        if !next.pending.is_empty() {
+            let pending = next.pending;
+            next.width += next.pending_width;
+            next.pending.clear();
+            next.pending_width = 0;
+            self.write_inner(pending.as_str(), next)?;
+        }
+        Ok(())
+    }
+
+    /// Hold back `s`, if the buffer is full the held back text and `s` are written early
+    fn push_pending(&mut self, s: &str, width: usize, next: &mut State) -> Result<(), W::Error> {
+        if next.pending.write_str(s).is_ok() {
+            next.pending_width += width;
+            return Ok(());
+        }
+        self.commit_pending(next)?;
+        next.width += width;
+        self.write_inner(s, next)
+    }
+
+    fn truncate(&mut self, next: &mut State) -> Result<(), W::Error> {
+        self.ellipsis.write_to(self.writer)?;
+        // the text that is replaced by the ellipsis may contain escape sequences like a style reset
+        let mut escapes = ArrayBuf::<64>::new();
+        for segment in AnsiScanner::new().segments(next.pending.as_str()) {
+            if let AnsiSegment::Escape(escape) = segment {
+                // can't fail, the escapes are a part of the pending text
+                let _ = escapes.write_str(escape);
+            }
+        }
+        next.pending = escapes;
+        next.pending_width = 0;
+        next.truncated = true;
+        self.state = *next;
+        self.commit_pending(next)
+    }
+
+    fn write_text(&mut self, text: &str, next: &mut State) -> Result<(), W::Error> {
+        // start of the text that can be written directly
+        let mut direct = 0;
+        for (i, c) in text.char_indices() {
+            if next.truncated {
+                return Ok(());
+            }
+            let end = i + c.len_utf8();
+            let char_width = if next.joined { 0 } else { char_width(c) };
+
+            if char_width == 0 && !next.pending.is_empty() {
+                next.joined = c == '\u{200D}';
+                self.push_pending(unsafe { text.get_unchecked(i..end) }, 0, next)?;
+                direct = end;
+            } else if next.width + next.pending_width + char_width > self.max_width {
+                self.write_inner(unsafe { text.get_unchecked(direct..i) }, next)?;
+                return self.truncate(next);
+            } else if next.pending.is_empty()
+                && next.width + char_width + self.ellipsis_width <= self.max_width
+            {
+                next.joined = c == '\u{200D}';
+                next.width += char_width;
+            } else {
+                self.write_inner(unsafe { text.get_unchecked(direct..i) }, next)?;
+                next.joined = c == '\u{200D}';
+                self.push_pending(unsafe { text.get_unchecked(i..end) }, char_width, next)?;
+                direct = end;
+            }
+        }
+        self.write_inner(unsafe { text.get_unchecked(direct..) }, next)
+    }
+}
+
+impl<W, E> Write for Truncate<'_, '_, W, E>
+where
+    W: Write + ?Sized,
+    E: WriteTo + ?Sized,
+{
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut next = self.state;
+        let mut scanner = next.scanner;
+        for segment in scanner.segments(s) {
+            match segment {
+                AnsiSegment::Text(text) => self.write_text(text, &mut next)?,
+                AnsiSegment::Escape(escape) if next.pending.is_empty() => {
+                    self.write_inner(escape, &next)?;
+                }
+                AnsiSegment::Escape(escape) => self.push_pending(escape, 0, &mut next)?,
+            }
+        }
+        next.scanner = scanner;
+        self.state = next;
+        Ok(())
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        self.writer.flush_hint();
+    }
+}
+
+impl<W, E> Flush for Truncate<'_, '_, W, E>
+where
+    W: Write + Flush + ?Sized,
+    E: WriteTo + ?Sized,
+{
+    type Error = <W as Flush>::Error;
+
+    /// Only flushes the writer, the held back text is kept until [`Truncate::finish`]
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+impl<W, E> Drop for Truncate<'_, '_, W, E>
+where
+    W: Write + ?Sized,
+    E: WriteTo + ?Sized,
+{
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+crate::declare_fmt_wrapper_struct! {
+    Truncated<const WIDTH: usize> FmtTruncate fmt_truncate,
+}
+
+impl<T, const WIDTH: usize> WriteTo for Truncated<T, WIDTH>
+where
+    T: Fmt + ?Sized,
+{
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        let mut truncate = Truncate::new(w, WIDTH);
+        self.0.fmt().write_to(&mut truncate)?;
+        truncate.finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{FmtTruncate, Truncate};
+    use crate::{
+        write::{Write, array_buf::ArrayBuf},
+        write_to::WriteTo,
+    };
+
+    fn truncated(s: &str, max_width: usize) -> (String, bool) {
+        let mut out = String::new();
+        let mut t = Truncate::new(&mut out, max_width);
+        let Ok(()) = t.write_str(s);
+        let Ok(()) = t.finish();
+        let truncated = t.is_truncated();
+        drop(t);
+        (out, truncated)
+    }
+
+    #[test]
+    fn truncate() {
+        assert_eq!(truncated("hello", 5), ("hello".into(), false));
+        assert_eq!(truncated("hello!", 5), ("hell…".into(), true));
+        #[cfg(feature = "unicode_width")]
+        assert_eq!(truncated("日本語", 4), ("日…".into(), true));
+        #[cfg(feature = "unicode_width")]
+        assert_eq!(truncated("e\u{301}e\u{301}e\u{301}", 2), ("e\u{301}…".into(), true));
+        assert_eq!(
+            truncated("\x1B[38;5;9mlong error\x1B[39m", 5),
+            ("\x1B[38;5;9mlong…\x1B[39m".into(), true)
+        );
+        assert_eq!(
+            truncated("abc\x1B[31md\x1B[0mef", 4),
+            ("abc\x1B[31m…\x1B[0m".into(), true)
+        );
+
+        let mut out = String::new();
+        let mut t = Truncate::with_ellipsis(&mut out, 6, "...");
+        let Ok(()) = t.write_str("abc");
+        let Ok(()) = t.write_str("defgh");
+        drop(t);
+        assert_eq!(out, "abc...");
+
+        let mut out = String::new();
+        let Ok(()) = FmtTruncate::<8>::fmt_truncate("a very long name").write_to(&mut out);
+        assert_eq!(out, "a very …");
+    }
+
+    #[test]
+    fn errors() {
+        let mut buf = ArrayBuf::<4>::new();
+        let mut t = Truncate::new(&mut buf, 10);
+        assert!(t.write_str("abcdef").is_err());
+        assert_eq!(t.width(), 0);
+        t.write_str("abc").unwrap();
+        assert_eq!(t.width(), 3);
+        drop(t);
+
+        buf.clear();
+        let mut t = Truncate::new(&mut buf, 4);
+        t.write_str("abcd").unwrap();
+        assert!(t.write_str("ef").is_err());
+        assert!(!t.is_truncated());
+        assert_eq!(t.width(), 4);
+        t.writer.clear();
+        t.write_str("ef").unwrap();
+        assert!(t.is_truncated());
+        drop(t);
+        assert_eq!(buf.as_str(), "…");
+    }
+}
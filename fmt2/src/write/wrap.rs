@@ -0,0 +1,291 @@
+use crate::{
+    ansi::{AnsiScanner, AnsiSegment},
+    utils::char_width,
+    write::{Flush, Write},
+};
+
+/// Writer that soft-wraps text on word boundaries so no line is wider than `width` columns
+///
+/// Width is measured in display columns (see [`char_width`]) and ANSI escape sequences have no width.
+/// Lines that are wrapped start with a hanging indent, so they line up under a prefix like `? ` or `error: `.
+/// Words longer than a line are broken up.
+///
+/// The current word is buffered until it's known where it fits, it is written on a newline,
+/// a space, [`Write::flush_hint`], [`Flush::flush`], [`Wrap::finish`] or when it's dropped.
+/// An error on a flush hint is returned from the next [`Write::write_str`], [`Flush::flush`] or [`Wrap::finish`],
+/// call [`Wrap::finish`] before it's dropped to get the errors of the last word.
+pub struct Wrap<'w, W>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+    width: usize,
+    hanging_indent: usize,
+    column: usize,
+    /// nothing has been written to the line since the last newline or soft wrap
+    line_empty: bool,
+    /// the line was started by a soft wrap, so leading spaces are dropped
+    soft_wrapped: bool,
+    spaces: usize,
+    word: alloc::string::String,
+    word_width: usize,
+    scanner: AnsiScanner,
+    /// Error of writing the word on a flush hint
+    error: Option<W::Error>,
+}
+
+impl<'w, W> Wrap<'w, W>
+where
+    W: Write + ?Sized,
+{
+    #[inline]
+    pub const fn new(writer: &'w mut W, width: usize) -> Self {
+        Self {
+            writer,
+            width,
+            hanging_indent: 0,
+            column: 0,
+            line_empty: true,
+            soft_wrapped: false,
+            spaces: 0,
+            word: alloc::string::String::new(),
+            word_width: 0,
+            scanner: AnsiScanner::new(),
+            error: None,
+        }
+    }
+
+    /// Wrap at the current width of the terminal
+    #[cfg(feature = "terminal_size")]
+    pub fn new_terminal_width(writer: &'w mut W) -> std::io::Result<Self> {
+        let (width, _) = crossterm::terminal::size()?;
+        Ok(Self::new(writer, usize::from(width)))
+    }
+
+    /// Indent lines that were wrapped by `hanging_indent` columns
+    #[inline]
+    #[must_use]
+    pub const fn with_hanging_indent(mut self, hanging_indent: usize) -> Self {
+        self.hanging_indent = hanging_indent;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Write the buffered word and the spaces after it
+    pub fn finish(&mut self) -> Result<(), W::Error> {
+        self.take_error()?;
+        self.place_word()?;
+        self.place_spaces()
+    }
+
+    #[inline]
+    fn take_error(&mut self) -> Result<(), W::Error> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+
+    #[inline]
+    const fn max_word_width(&self) -> usize {
+        let w = self.width.saturating_sub(self.hanging_indent);
+        if w == 0 { 1 } else { w }
+    }
+
+    fn write_spaces(&mut self, mut n: usize) -> Result<(), W::Error> {
+        const SPACES: &str = "                ";
+        while n > 0 {
+            let len = n.min(SPACES.len());
+            self.writer.write_str(unsafe { SPACES.get_unchecked(..len) })?;
+            n -= len;
+        }
+        Ok(())
+    }
+
+    fn soft_wrap(&mut self) -> Result<(), W::Error> {
+        self.writer.write_str("\n")?;
+        self.write_spaces(self.hanging_indent)?;
+        self.column = self.hanging_indent;
+        self.line_empty = true;
+        self.soft_wrapped = true;
+        self.spaces = 0;
+        Ok(())
+    }
+
+    fn place_word(&mut self) -> Result<(), W::Error> {
+        if self.word.is_empty() {
+            return Ok(());
+        }
+        if !self.line_empty && self.column + self.spaces + self.word_width > self.width {
+            self.soft_wrap()?;
+        }
+        if self.line_empty && self.soft_wrapped {
+            self.spaces = 0;
+        }
+        self.write_spaces(self.spaces)?;
+        self.column += self.spaces;
+        self.spaces = 0;
+
+        self.writer.write_str(&self.word)?;
+        self.column += self.word_width;
+        self.line_empty = false;
+        self.word.clear();
+        self.word_width = 0;
+        Ok(())
+    }
+
+    /// Write the spaces at the end of the text or before a newline, they are only dropped at a soft wrap
+    fn place_spaces(&mut self) -> Result<(), W::Error> {
+        if !(self.line_empty && self.soft_wrapped) {
+            // spaces that don't fit would be where the line is wrapped
+            let spaces = self.spaces.min(self.width.saturating_sub(self.column));
+            self.write_spaces(spaces)?;
+            self.column += spaces;
+        }
+        self.spaces = 0;
+        Ok(())
+    }
+
+    fn write_text(&mut self, text: &str) -> Result<(), W::Error> {
+        for c in text.chars() {
+            match c {
+                '\n' => {
+                    self.place_word()?;
+                    self.place_spaces()?;
+                    self.writer.write_str("\n")?;
+                    self.column = 0;
+                    self.line_empty = true;
+                    self.soft_wrapped = false;
+                }
+                ' ' => {
+                    self.place_word()?;
+                    self.spaces += 1;
+                }
+                c => {
+                    let width = char_width(c);
+                    if !self.word.is_empty() && self.word_width + width > self.max_word_width() {
+                        self.place_word()?;
+                        self.soft_wrap()?;
+                    }
+                    self.word.push(c);
+                    self.word_width += width;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W> Write for Wrap<'_, W>
+where
+    W: Write + ?Sized,
+{
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.take_error()?;
+        let mut scanner = self.scanner;
+        let result = scanner.segments(s).try_for_each(|segment| match segment {
+            AnsiSegment::Text(text) => self.write_text(text),
+            AnsiSegment::Escape(escape) => {
+                self.word.push_str(escape);
+                Ok(())
+            }
+        });
+        // also on errors, the segments before the error were written
+        self.scanner = scanner;
+        result
+    }
+
+    #[inline]
+    fn flush_hint(&mut self) {
+        match self.place_word() {
+            Ok(()) => self.writer.flush_hint(),
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
+
+impl<W> Flush for Wrap<'_, W>
+where
+    W: Write + Flush<Error = <W as Write>::Error> + ?Sized,
+{
+    type Error = <W as Flush>::Error;
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.take_error()?;
+        self.place_word()?;
+        self.writer.flush()
+    }
+}
+
+impl<W> Drop for Wrap<'_, W>
+where
+    W: Write + ?Sized,
+{
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Wrap;
+    use crate::write::{Write, array_buf::ArrayBuf};
+
+    #[test]
+    fn wrap() {
+        let mut s = String::new();
+        {
+            let mut w = Wrap::new(&mut s, 10).with_hanging_indent(2);
+            let Ok(()) = w.write_str("? the quick brown");
+            let Ok(()) = w.write_str(" fox \x1B[38;5;9mjumps\x1B[39m over\nthe lazy dog");
+        }
+        assert_eq!(
+            s,
+            "? the\n  quick\n  brown\n  fox\n  \x1B[38;5;9mjumps\x1B[39m\n  over\nthe lazy\n  dog"
+        );
+
+        let mut s = String::new();
+        {
+            let mut w = Wrap::new(&mut s, 4);
+            let Ok(()) = w.write_str("abcdefghij k");
+        }
+        assert_eq!(s, "abcd\nefgh\nij k");
+    }
+
+    #[test]
+    fn trailing_spaces() {
+        let mut s = String::new();
+        {
+            let mut w = Wrap::new(&mut s, 10);
+            let Ok(()) = w.write_str("? ");
+        }
+        assert_eq!(s, "? ");
+
+        let mut s = String::new();
+        let mut w = Wrap::new(&mut s, 6);
+        let Ok(()) = w.write_str("a b  \nc   ");
+        let Ok(()) = w.write_str("long words");
+        let Ok(()) = w.finish();
+        drop(w);
+        assert_eq!(s, "a b  \nc\nlong\nwords");
+    }
+
+    #[test]
+    fn errors() {
+        let mut buf = ArrayBuf::<4>::new();
+        buf.write_str("xyz").unwrap();
+        let mut w = Wrap::new(&mut buf, 10);
+        assert!(crate::fmt! { (? w) => "ab" }.is_ok());
+        assert!(w.write_str("c").is_err());
+        w.writer.clear();
+        assert!(w.write_str("c").is_ok());
+        assert!(w.finish().is_ok());
+        drop(w);
+        assert_eq!(buf.as_str(), "abc");
+    }
+}
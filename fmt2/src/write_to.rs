@@ -1,4 +1,15 @@
-use crate::{str::FmtStaticStrImpl, write::Write};
+use crate::{
+    str::FmtStaticStrImpl,
+    write::{Write, counting::CountingWriter},
+};
+
+pub mod containers;
+mod debug;
+pub mod float;
+pub mod int;
+pub mod list;
+pub mod pad;
+pub mod table;
 
 pub trait WriteTo {
     const ENDS_IN_NEWLINE: bool = false;
@@ -12,6 +23,22 @@ pub trait WriteTo {
     fn len_hint(&self) -> usize {
         0
     }
+
+    /// Measure the output by writing it to a [`CountingWriter`]
+    ///
+    /// This does all of the formatting work, unlike [`WriteTo::len_hint`] which is only a lower bound
+    #[inline]
+    fn measure(&self) -> CountingWriter {
+        let mut counter = CountingWriter::new();
+        let Ok(()) = self.write_to(&mut counter);
+        counter
+    }
+
+    /// Exact length of the output in bytes, see [`WriteTo::measure`]
+    #[inline]
+    fn exact_len(&self) -> usize {
+        self.measure().bytes
+    }
 }
 
 impl WriteTo for str {
@@ -27,6 +54,77 @@ impl WriteTo for str {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl WriteTo for alloc::string::String {
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(self)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Object safe version of [`WriteTo`], for `dyn DynWriteTo` in collections of mixed types like `Vec<Box<dyn DynWriteTo>>`
+///
+/// It's implemented for everything that implements [`WriteTo`] and `dyn DynWriteTo` implements [`WriteTo`].
+/// Types that only implement [`Fmt`], like `bool`, can be erased as a reference, because `&T` implements [`WriteTo`].
+/// The writer is erased to a [`core::fmt::Write`], but [`Write::std_write_adapter`] keeps the error of the writer,
+/// so writing a `dyn DynWriteTo` still returns the concrete `W::Error`.
+pub trait DynWriteTo {
+    fn dyn_write_to(&self, w: &mut dyn core::fmt::Write) -> core::fmt::Result;
+
+    /// See [`WriteTo::len_hint`]
+    fn dyn_len_hint(&self) -> usize;
+}
+
+impl<T> DynWriteTo for T
+where
+    T: WriteTo + ?Sized,
+{
+    #[inline]
+    fn dyn_write_to(&self, w: &mut dyn core::fmt::Write) -> core::fmt::Result {
+        self.write_to(w)
+    }
+
+    #[inline]
+    fn dyn_len_hint(&self) -> usize {
+        self.len_hint()
+    }
+}
+
+macro_rules! impl_write_to_for_dyn_write_to_internal {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl WriteTo for $ty {
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    w.std_write_adapter(|w| self.dyn_write_to(w))
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    self.dyn_len_hint()
+                }
+            }
+        )*
+    };
+}
+
+impl_write_to_for_dyn_write_to_internal! {
+    dyn DynWriteTo + '_,
+    dyn DynWriteTo + Send + '_,
+    dyn DynWriteTo + Send + Sync + '_,
+}
+
 impl<T> WriteTo for Debug<[T]>
 where
     T: FmtDebug,
@@ -83,7 +181,8 @@ where
 
 #[macro_export]
 macro_rules! declare_fmt_wrapper_struct {
-    { $Struct:ident $(<$(const $CONST:ident : $ConstType:ty),*>)? $Trait:ident $fn:ident $(, $($($rest:tt)+)?)? } => {
+    { $(#[$meta:meta])* $Struct:ident $(<$(const $CONST:ident : $ConstType:ty),*>)? $Trait:ident $fn:ident $(, $($($rest:tt)+)?)? } => {
+        $(#[$meta])*
         pub struct $Struct<T $($(, const $CONST: $ConstType)*)?>(T)
         where
             T: ?Sized,
@@ -129,19 +228,6 @@ macro_rules! declare_std_write_to_wrapper_struct_internal {
     };
 }
 
-macro_rules! impl_fmt_trait_internal {
-    { $StdTrait:ident $std_fn:ident => $Trait:ident $fn:ident => $($ty:ty)*} => {
-        $(
-            impl $Trait for $ty {
-                #[inline]
-                fn $fn(&self) -> &(impl $crate::write_to::WriteTo + ?Sized) {
-                    $StdTrait::$std_fn(self)
-                }
-            }
-        )*
-    };
-}
-
 declare_fmt_wrapper_struct! {
     Debug   FmtDebug    fmt_debug,
     Binary  FmtBinary   fmt_binary,
@@ -163,11 +249,6 @@ declare_std_write_to_wrapper_struct_internal! {
     StdPrecision<const PRECISION: u8>   FmtStdPrecision fmt_std_precision   => Display write_std_precision,
 }
 
-impl_fmt_trait_internal! { FmtStdDisplay    fmt_std_display => Fmt          fmt         => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-impl_fmt_trait_internal! { FmtStdDebug      fmt_std_debug   => FmtDebug     fmt_debug   => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-impl_fmt_trait_internal! { FmtStdBinary     fmt_std_binary  => FmtBinary    fmt_binary  => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-impl_fmt_trait_internal! { FmtStdOctal      fmt_std_octal   => FmtOctal     fmt_octal   => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-impl_fmt_trait_internal! { FmtStdHex        fmt_std_hex     => FmtHex       fmt_hex     => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
 
 //
 // impl<'t, I, T> WriteTo for Iterator<I>
@@ -200,10 +281,7 @@ where
     where
         W: Write + ?Sized,
     {
-        for t in self.0.clone() {
-            t.write_to(w)?;
-        }
-        Ok(())
+        self.write_separated(w, |_, _| Ok(()))
     }
 
     #[inline]
@@ -212,21 +290,32 @@ where
     }
 }
 
-impl WriteTo for core::fmt::Arguments<'_> {
-    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+impl<'t, I, T> Iterator<I>
+where
+    I: core::iter::Iterator<Item = &'t T> + Clone,
+    T: WriteTo + ?Sized + 't,
+{
+    /// Write the elements with `separator` called before each one with its index
+    #[inline]
+    pub(crate) fn write_separated<W, S>(&self, w: &mut W, mut separator: S) -> Result<(), W::Error>
     where
         W: Write + ?Sized,
+        S: FnMut(&mut W, usize) -> Result<(), W::Error>,
     {
-        w.write_std_args_ref(self)
+        for (index, t) in self.0.clone().enumerate() {
+            separator(w, index)?;
+            t.write_to(w)?;
+        }
+        Ok(())
     }
 }
 
-impl WriteTo for Debug<str> {
+impl WriteTo for core::fmt::Arguments<'_> {
     fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
     where
         W: Write + ?Sized,
     {
-        w.write_str(&self.0)
+        w.write_std_args_ref(self)
     }
 }
 
@@ -259,43 +348,126 @@ impl FmtPrecision<5> for bool {
     }
 }
 
-// impl FmtDebug for bool {
-//     type Target = Self;
-//     fn fmt_debug(&self) -> &Self::Target {
-//         self
-//     }
-// }
+/// [`WriteTo`] from a closure, see [`from_fn`]
+pub struct FromFn<
+    F,
+    L = fn() -> usize,
+    const ENDS_IN_NEWLINE: bool = false,
+    const MIN_SIZE: usize = 0,
+> where
+    F: Fn(&mut dyn core::fmt::Write) -> core::fmt::Result,
+    L: Fn() -> usize,
+{
+    write: F,
+    len_hint: L,
+}
 
-// pub trait WriteToFor<W>
-// where
-//     W: crate::write::Write + ?Sized,
-// {
-//     fn write_to_for(&self, w: &mut W) -> Result<(), W::Error>;
-// }
-//
-// impl<F, W> WriteToFor<W> for F
-// where
-//     F: Fn(&mut W) -> Result<(), W::Error>,
-//     W: crate::write::Write,
-// {
-//     fn write_to_for(&self, w: &mut W) -> Result<(), W::Error> {
-//         self(w)
-//     }
-// }
+/// [`WriteTo`] that calls `write` with every writer, for loops, matches and early returns in the middle of [`fmt!`](crate::fmt)
+///
+/// The writer is erased to a [`core::fmt::Write`] like in [`DynWriteTo`], which [`fmt!`](crate::fmt) can write to with `(? w)`.
+/// Errors of the writer are returned from [`WriteTo::write_to`] as the concrete error.
+/// An error `write` returns on its own, without the writer failing, only ends the output early,
+/// what was written until then is kept and [`WriteTo::write_to`] returns `Ok`.
+#[inline]
+pub fn from_fn<F>(write: F) -> FromFn<F>
+where
+    F: Fn(&mut dyn core::fmt::Write) -> core::fmt::Result,
+{
+    FromFn {
+        write,
+        len_hint: || 0,
+    }
+}
+
+impl<F, L, const ENDS_IN_NEWLINE: bool, const MIN_SIZE: usize>
+    FromFn<F, L, ENDS_IN_NEWLINE, MIN_SIZE>
+where
+    F: Fn(&mut dyn core::fmt::Write) -> core::fmt::Result,
+    L: Fn() -> usize,
+{
+    /// See [`WriteTo::len_hint`]
+    #[inline]
+    pub fn with_len_hint<L2>(self, len_hint: L2) -> FromFn<F, L2, ENDS_IN_NEWLINE, MIN_SIZE>
+    where
+        L2: Fn() -> usize,
+    {
+        FromFn {
+            write: self.write,
+            len_hint,
+        }
+    }
+
+    /// The output always ends in a newline, see [`WriteTo::ENDS_IN_NEWLINE`]
+    #[inline]
+    pub fn ends_in_newline(self) -> FromFn<F, L, true, MIN_SIZE> {
+        FromFn {
+            write: self.write,
+            len_hint: self.len_hint,
+        }
+    }
 
-#[cfg(feature = "std")]
+    /// The output is always at least `N` bytes long, see [`WriteTo::MIN_SIZE`]
+    #[inline]
+    pub fn with_min_size<const N: usize>(self) -> FromFn<F, L, ENDS_IN_NEWLINE, N> {
+        FromFn {
+            write: self.write,
+            len_hint: self.len_hint,
+        }
+    }
+}
+
+impl<F, L, const ENDS_IN_NEWLINE: bool, const MIN_SIZE: usize> WriteTo
+    for FromFn<F, L, ENDS_IN_NEWLINE, MIN_SIZE>
+where
+    F: Fn(&mut dyn core::fmt::Write) -> core::fmt::Result,
+    L: Fn() -> usize,
+{
+    const ENDS_IN_NEWLINE: bool = ENDS_IN_NEWLINE;
+    const MIN_SIZE: usize = MIN_SIZE;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        let mut adapter = crate::write::adapter::StdWriteAdapter::new(w);
+        // an error of the closure itself stops it, but only an error of the writer is returned
+        let _ = (self.write)(&mut adapter);
+        adapter.into_result()
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        (self.len_hint)().max(MIN_SIZE)
+    }
+}
+
+#[cfg(feature = "alloc")]
 pub trait ToString {
-    fn to_string(&self) -> ::std::string::String;
+    fn to_string(&self) -> alloc::string::String;
+
+    /// Measure the output first, then allocate exactly once
+    ///
+    /// Better than [`ToString::to_string`] for large outputs where [`WriteTo::len_hint`] is inaccurate,
+    /// but it formats everything twice
+    fn to_string_exact(&self) -> alloc::string::String;
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<T> ToString for T
 where
     T: Fmt + ?Sized,
 {
-    fn to_string(&self) -> String {
+    fn to_string(&self) -> alloc::string::String {
+        let wt = self.fmt();
+        let mut s = alloc::string::String::with_capacity(wt.len_hint());
+        let Ok(()) = s.write(wt);
+        s
+    }
+
+    fn to_string_exact(&self) -> alloc::string::String {
         let wt = self.fmt();
-        let mut s = String::with_capacity(wt.len_hint());
+        let mut s = alloc::string::String::with_capacity(wt.exact_len());
         let Ok(()) = s.write(wt);
         s
     }
@@ -366,34 +538,146 @@ where
     }
 }
 
+/// Adapter that implements [`core::fmt::Display`] for a [`WriteTo`], for `format!`, `println!` and other [`core::fmt`] APIs
+///
+/// `DisplayAdapter(&value)` works for everything that implements [`Fmt`]. For the other direction, see [`FmtStdDisplay`].
+#[derive(Clone, Copy)]
+pub struct DisplayAdapter<T>(pub T)
+where
+    T: WriteTo;
+
+impl<T> core::fmt::Display for DisplayAdapter<T>
+where
+    T: WriteTo,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Write::write(f, &self.0)
+    }
+}
+
+/// Implement [`WriteTo`] with [`core::fmt::Display`]
+///
+/// Types are separated by `;`, generics are lifetimes and type names and the bounds go in the where clause,
+/// like `impl Version; impl<'a, T> Labeled<'a, T> where T: core::fmt::Display;`
 #[macro_export]
 macro_rules! impl_write_to_for_std_display {
-	{ $($ty:ident $write_to_fn:ident),* $(,)? } => {
-		$(
-			impl $crate::write_to::WriteTo for $name {
-				#[inline]
-				fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
-					where
-						W: $crate::write::Write + ?Sized {
-					$crate::write::Write::write_std_display(w, self)
-				}
-			}
-		)*
-	};
+    { $($tt:tt)* } => {
+        $crate::impl_for_types_internal! { @impl_write_to_for_std_display_internal $($tt)* }
+    };
 }
 
+/// Implement [`core::fmt::Display`] with [`Fmt`], with the same syntax as [`impl_write_to_for_std_display!`](crate::impl_write_to_for_std_display)
+///
+/// A list of types without generics, like `impl_std_display_for_write_to! { A, B }`, also works.
 #[macro_export]
 macro_rules! impl_std_display_for_write_to {
-	{ $($name:ty),* $(,)? } => {
-		$(
-			impl ::core::fmt::Display for $name {
-				#[inline]
-				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-					$crate::write::Write::write(f, self)
-				}
-			}
-		)*
-	};
+    { impl $($tt:tt)* } => {
+        $crate::impl_for_types_internal! { @impl_std_display_for_write_to_internal impl $($tt)* }
+    };
+    { $($ty:ty),* $(,)? } => {
+        $crate::impl_for_types_internal! { @impl_std_display_for_write_to_internal $(impl $ty;)* }
+    };
+}
+
+/// Implement [`core::fmt::Debug`] with [`Fmt`], so it's written the same as with [`core::fmt::Display`],
+/// with the same syntax as [`impl_std_display_for_write_to!`](crate::impl_std_display_for_write_to)
+#[macro_export]
+macro_rules! impl_std_debug_for_write_to {
+    { impl $($tt:tt)* } => {
+        $crate::impl_for_types_internal! { @impl_std_debug_for_write_to_internal impl $($tt)* }
+    };
+    { $($ty:ty),* $(,)? } => {
+        $crate::impl_for_types_internal! { @impl_std_debug_for_write_to_internal $(impl $ty;)* }
+    };
+}
+
+/// Splits `impl<generics> Type where bounds;` items (the last `;` is optional) and calls `$callback` with each of them
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_for_types_internal {
+    { @$callback:ident } => {};
+    { @$callback:ident impl<$($generic:tt),*> $ty:ty where $($rest:tt)* } => {
+        $crate::impl_for_types_internal! { @@where $callback [$($generic),*] [$ty] [] $($rest)* }
+    };
+    { @$callback:ident impl<$($generic:tt),*> $ty:ty; $($rest:tt)* } => {
+        $crate::$callback! { [$($generic),*] [$ty] [] }
+        $crate::impl_for_types_internal! { @$callback $($rest)* }
+    };
+    { @$callback:ident impl $ty:ty where $($rest:tt)* } => {
+        $crate::impl_for_types_internal! { @@where $callback [] [$ty] [] $($rest)* }
+    };
+    { @$callback:ident impl $ty:ty; $($rest:tt)* } => {
+        $crate::$callback! { [] [$ty] [] }
+        $crate::impl_for_types_internal! { @$callback $($rest)* }
+    };
+    { @$callback:ident impl<$($generic:tt),*> $ty:ty } => {
+        $crate::$callback! { [$($generic),*] [$ty] [] }
+    };
+    { @$callback:ident impl $ty:ty } => {
+        $crate::$callback! { [] [$ty] [] }
+    };
+    { @@where $callback:ident [$($generic:tt),*] [$ty:ty] [$($where:tt)*] } => {
+        $crate::$callback! { [$($generic),*] [$ty] [$($where)*] }
+    };
+    { @@where $callback:ident [$($generic:tt),*] [$ty:ty] [$($where:tt)*] ; $($rest:tt)* } => {
+        $crate::$callback! { [$($generic),*] [$ty] [$($where)*] }
+        $crate::impl_for_types_internal! { @$callback $($rest)* }
+    };
+    { @@where $callback:ident [$($generic:tt),*] [$ty:ty] [$($where:tt)*] $next:tt $($rest:tt)* } => {
+        $crate::impl_for_types_internal! { @@where $callback [$($generic),*] [$ty] [$($where)* $next] $($rest)* }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_write_to_for_std_display_internal {
+    { [$($generic:tt),*] [$ty:ty] [$($where:tt)*] } => {
+        impl<$($generic),*> $crate::write_to::WriteTo for $ty
+        where
+            $($where)*
+        {
+            #[inline]
+            fn write_to<W>(&self, w: &mut W) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::write::Write + ?Sized,
+            {
+                $crate::write::Write::write_std_display(w, self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_std_display_for_write_to_internal {
+    { [$($generic:tt),*] [$ty:ty] [$($where:tt)*] } => {
+        impl<$($generic),*> ::core::fmt::Display for $ty
+        where
+            $($where)*
+        {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::write::Write::write(f, $crate::write_to::Fmt::fmt(self))
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_std_debug_for_write_to_internal {
+    { [$($generic:tt),*] [$ty:ty] [$($where:tt)*] } => {
+        impl<$($generic),*> ::core::fmt::Debug for $ty
+        where
+            $($where)*
+        {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::write::Write::write(f, $crate::write_to::Fmt::fmt(self))
+            }
+        }
+    };
 }
 
 #[allow(
@@ -407,6 +691,150 @@ macro_rules! impl_std_display_for_write_to {
 )]
 #[cfg(test)]
 mod tests {
+    use super::WriteTo;
+    use crate::write::Write;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn alloc_sources_and_sinks() {
+        use super::ToString;
+
+        let boxed: Box<str> = Box::from("box");
+        let cow = std::borrow::Cow::Borrowed("cow");
+        let string = String::from("string");
+        let s = crate::fmt! { { str } => {boxed} " " {cow} " " {string} };
+        assert_eq!(s, "box cow string");
+        assert_eq!(ToString::to_string(&String::from("abc")), "abc");
+
+        let mut v: Vec<u8> = Vec::new();
+        let Ok(()) = v.write(&boxed);
+        assert_eq!(v, b"box");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dyn_write_to() {
+        use super::DynWriteTo;
+        use crate::write::array_buf::{ArrayBuf, CapacityError};
+
+        let parts: Vec<Box<dyn DynWriteTo>> =
+            vec![Box::new(String::from("a")), Box::new(1), Box::new(&true)];
+        let mut s = String::new();
+        for part in &parts {
+            let Ok(()) = s.write(part);
+        }
+        assert_eq!(s, "a1true");
+
+        let mut buf = ArrayBuf::<2>::new();
+        let part: &dyn DynWriteTo = &String::from("abc");
+        let result: Result<(), CapacityError> = buf.write(part);
+        assert_eq!(
+            result,
+            Err(CapacityError {
+                len: 3,
+                remaining: 2
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn measure() {
+        use super::ToString;
+
+        let a = 12345;
+        let s = "héllo";
+        let wt = crate::fmt! { {} => {@a} " " {@s} };
+        let m = wt.measure();
+        assert_eq!(m.bytes, 12);
+        assert_eq!(m.chars, 11);
+        assert_eq!(m.width, 11);
+        assert_eq!(wt.exact_len(), 12);
+
+        let string = ToString::to_string_exact(wt);
+        assert_eq!(string, "12345 héllo");
+        assert_eq!(string.capacity(), 12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_fn() {
+        let items = ["a", "b", "c"];
+        let list = super::from_fn(|w| {
+            for (i, item) in items.iter().enumerate() {
+                if *item == "c" {
+                    return Ok(());
+                }
+                crate::fmt! { (? w) => {i} "=" {item} "\n" }?;
+            }
+            Ok(())
+        })
+        .with_len_hint(|| 8)
+        .ends_in_newline()
+        .with_min_size::<4>();
+        assert_eq!(list.len_hint(), 8);
+        assert_eq!(crate::fmt! { { str } => "[" {list} "]" }, "[0=a\n1=b\n]");
+
+        let mut buf = crate::write::array_buf::ArrayBuf::<3>::new();
+        assert!(buf.write(&list).is_err());
+
+        let early = super::from_fn(|w| {
+            crate::fmt! { (? w) => "a" }?;
+            Err(core::fmt::Error)
+        });
+        assert_eq!(crate::fmt! { { str } => {early} "b" }, "ab");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_bridge() {
+        use super::{DisplayAdapter, Fmt};
+
+        struct Version(u8, u8);
+        impl WriteTo for Version {
+            crate::fmt! { [s] => {s.0} "." {s.1} }
+        }
+
+        struct Labeled<'a, T>(&'a str, T);
+        impl<T> WriteTo for Labeled<'_, T>
+        where
+            T: Fmt,
+        {
+            crate::fmt! { [s] => {s.0} ": " {s.1} }
+        }
+
+        struct Celsius(f32);
+        impl core::fmt::Display for Celsius {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}°C", self.0)
+            }
+        }
+
+        enum Level {
+            Low,
+        }
+        crate::impl_const_str_for! { Level => "low" }
+
+        crate::impl_std_display_for_write_to! { Version }
+        crate::impl_std_debug_for_write_to! {
+            impl Version;
+            impl<'a, T> Labeled<'a, T> where T: Fmt;
+        }
+        crate::impl_std_display_for_write_to! { impl<'a, T> Labeled<'a, T> where T: Fmt }
+        crate::impl_write_to_for_std_display! { impl Celsius }
+        crate::impl_display_for_str! { Level }
+
+        let version = Version(1, 2);
+        let label = Labeled("temp", Celsius(21.5));
+        assert_eq!(
+            format!("{version} {version:?} {label} {label:?}"),
+            "1.2 1.2 temp: 21.5°C temp: 21.5°C"
+        );
+        assert_eq!(format!("[{}]", DisplayAdapter(&true)), "[true]");
+        assert_eq!(format!("[{:>5}]", Level::Low), "[  low]");
+        assert_eq!(crate::fmt! { { str } => {label} }, "temp: 21.5°C");
+    }
+
     // use core::borrow::Borrow;
 
     // use crate::{str::FmtStaticStr, write_to::WriteTo};
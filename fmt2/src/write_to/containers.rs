@@ -0,0 +1,407 @@
+use crate::{
+    write::Write,
+    write_to::{Fmt, FmtAdvanced, WriteTo},
+};
+
+impl WriteTo for char {
+    const MIN_SIZE: usize = 1;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(self.encode_utf8(&mut [0; 4]))
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.len_utf8()
+    }
+}
+
+macro_rules! impl_write_to_for_pointer_internal {
+    ($($(#[$meta:meta])* $ty:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl<T> WriteTo for $ty
+            where
+                T: FmtAdvanced + ?Sized,
+            {
+                const ENDS_IN_NEWLINE: bool = T::Target::ENDS_IN_NEWLINE;
+                const MIN_SIZE: usize = T::Target::MIN_SIZE;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    (**self).fmt_advanced().write_to(w)
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    (**self).fmt_advanced().len_hint()
+                }
+            }
+        )*
+    };
+}
+
+impl_write_to_for_pointer_internal! {
+    &T,
+    &mut T,
+    #[cfg(feature = "alloc")]
+    alloc::boxed::Box<T>,
+    #[cfg(feature = "alloc")]
+    alloc::rc::Rc<T>,
+    #[cfg(feature = "alloc")]
+    alloc::sync::Arc<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<B> WriteTo for alloc::borrow::Cow<'_, B>
+where
+    B: alloc::borrow::ToOwned + FmtAdvanced + ?Sized,
+{
+    const ENDS_IN_NEWLINE: bool = B::Target::ENDS_IN_NEWLINE;
+    const MIN_SIZE: usize = B::Target::MIN_SIZE;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        (**self).fmt_advanced().write_to(w)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        (**self).fmt_advanced().len_hint()
+    }
+}
+
+/// Writes the value of `Some` and nothing for `None`, see [`Or`] to write something else
+impl<T> WriteTo for Option<T>
+where
+    T: Fmt,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match self {
+            Some(value) => value.fmt().write_to(w),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.as_ref().map_or(0, |value| value.fmt().len_hint())
+    }
+}
+
+/// Writes the value of `Ok` or the error, see [`Or`] to write something else for errors
+impl<T, E> WriteTo for Result<T, E>
+where
+    T: Fmt,
+    E: Fmt,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match self {
+            Ok(value) => value.fmt().write_to(w),
+            Err(err) => err.fmt().write_to(w),
+        }
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        match self {
+            Ok(value) => value.fmt().len_hint(),
+            Err(err) => err.fmt().len_hint(),
+        }
+    }
+}
+
+/// An [`Option`] or [`Result`] that writes `default` for `None` or `Err`, like `{x; or "-"}` in [`fmt!`](crate::fmt)
+pub struct Or<'t, T, D>
+where
+    T: ?Sized,
+    D: WriteTo + ?Sized,
+{
+    value: &'t T,
+    default: &'t D,
+}
+
+impl<'t, T, D> Or<'t, T, D>
+where
+    T: ?Sized,
+    D: WriteTo + ?Sized,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(value: &'t T, default: &'t D) -> Self {
+        Self { value, default }
+    }
+}
+
+impl<T, D> WriteTo for Or<'_, Option<T>, D>
+where
+    T: Fmt,
+    D: WriteTo + ?Sized,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match self.value {
+            Some(value) => value.fmt().write_to(w),
+            None => self.default.write_to(w),
+        }
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        match self.value {
+            Some(value) => value.fmt().len_hint(),
+            None => self.default.len_hint(),
+        }
+    }
+}
+
+impl<T, E, D> WriteTo for Or<'_, Result<T, E>, D>
+where
+    T: Fmt,
+    D: WriteTo + ?Sized,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match self.value {
+            Ok(value) => value.fmt().write_to(w),
+            Err(_) => self.default.write_to(w),
+        }
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        match self.value {
+            Ok(value) => value.fmt().len_hint(),
+            Err(_) => self.default.len_hint(),
+        }
+    }
+}
+
+/// Default separator of tuples and arrays
+pub const SEPARATOR: &str = ", ";
+
+/// Tuples and arrays, which are written with a separator between the elements
+pub trait WriteSeparated {
+    fn write_separated<W, S>(&self, w: &mut W, separator: &S) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+        S: WriteTo + ?Sized;
+
+    /// See [`WriteTo::len_hint`]
+    fn len_hint_separated<S>(&self, separator: &S) -> usize
+    where
+        S: WriteTo + ?Sized;
+}
+
+/// Tuple or array with a custom separator, like `{x; sep " | "}` in [`fmt!`](crate::fmt)
+pub struct Separated<'t, T, S>
+where
+    T: WriteSeparated + ?Sized,
+    S: WriteTo + ?Sized,
+{
+    value: &'t T,
+    separator: &'t S,
+}
+
+impl<'t, T, S> Separated<'t, T, S>
+where
+    T: WriteSeparated + ?Sized,
+    S: WriteTo + ?Sized,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(value: &'t T, separator: &'t S) -> Self {
+        Self { value, separator }
+    }
+}
+
+impl<T, S> WriteTo for Separated<'_, T, S>
+where
+    T: WriteSeparated + ?Sized,
+    S: WriteTo + ?Sized,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.value.write_separated(w, self.separator)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.value.len_hint_separated(self.separator)
+    }
+}
+
+impl<T, const N: usize> WriteSeparated for [T; N]
+where
+    T: Fmt,
+{
+    fn write_separated<W, S>(&self, w: &mut W, separator: &S) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+        S: WriteTo + ?Sized,
+    {
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                separator.write_to(w)?;
+            }
+            value.fmt().write_to(w)?;
+        }
+        Ok(())
+    }
+
+    fn len_hint_separated<S>(&self, separator: &S) -> usize
+    where
+        S: WriteTo + ?Sized,
+    {
+        let values: usize = self.iter().map(|value| value.fmt().len_hint()).sum();
+        values + N.saturating_sub(1) * separator.len_hint()
+    }
+}
+
+/// Elements separated by [`SEPARATOR`], see [`Separated`] for other separators
+impl<T, const N: usize> WriteTo for [T; N]
+where
+    T: Fmt,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.write_separated(w, SEPARATOR)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.len_hint_separated(SEPARATOR)
+    }
+}
+
+macro_rules! impl_write_separated_for_tuple_internal {
+    ($(($($T:ident $i:tt),+))*) => {
+        $(
+            impl<$($T),+> WriteSeparated for ($($T,)+)
+            where
+                $($T: Fmt,)+
+            {
+                fn write_separated<W, S>(&self, w: &mut W, separator: &S) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                    S: WriteTo + ?Sized,
+                {
+                    $(
+                        if $i > 0 {
+                            separator.write_to(w)?;
+                        }
+                        self.$i.fmt().write_to(w)?;
+                    )+
+                    Ok(())
+                }
+
+                fn len_hint_separated<S>(&self, separator: &S) -> usize
+                where
+                    S: WriteTo + ?Sized,
+                {
+                    let separators = [$($i),+].len() - 1;
+                    0 $(+ self.$i.fmt().len_hint())+ + separators * separator.len_hint()
+                }
+            }
+
+            /// Elements separated by [`SEPARATOR`], see [`Separated`] for other separators
+            impl<$($T),+> WriteTo for ($($T,)+)
+            where
+                $($T: Fmt,)+
+            {
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    self.write_separated(w, SEPARATOR)
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    self.len_hint_separated(SEPARATOR)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_separated_for_tuple_internal! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+    #[test]
+    fn containers() {
+        let name: Option<&str> = Some("ferris");
+        let missing: Option<u8> = None;
+        let parsed = "x".parse::<u8>();
+        let reference = &&name;
+        let boxed: Box<u16> = Box::new(5);
+        let shared = (
+            Rc::new(true),
+            Arc::<str>::from("arc"),
+            Cow::<str>::Borrowed("cow"),
+        );
+        let point = (1, 'x', 2.5);
+        let bits = [1u8, 0, 1];
+        let nested = ((1, 2), [3, 4]);
+
+        assert_eq!(
+            crate::fmt! { { str } => {name} " " {reference} " " {missing} "|" {boxed} " " {shared} },
+            "ferris ferris |5 true, arc, cow"
+        );
+        assert_eq!(
+            crate::fmt! { { str } => {missing; or "-"} " " {parsed; or "invalid"} },
+            "- invalid"
+        );
+        assert_eq!(
+            crate::fmt! { { str } => "(" {point} ") [" {bits; sep ""} "] " {nested; sep " / "} },
+            "(1, x, 2.5) [101] 1, 2 / 3, 4"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    write::Write,
+    write_to::{Debug, FmtDebug, WriteTo},
+};
+
+/// Write `s` escaped like [`core::fmt::Debug`] does inside of quotes
+///
+/// Only the quote that is used around `s` is escaped, so `"` inside of strings and `'` inside of chars.
+fn write_escaped<W>(w: &mut W, s: &str, quote: char) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+{
+    // start of the text that can be written directly
+    let mut direct = 0;
+    for (i, c) in s.char_indices() {
+        if matches!(c, ' '..='~') && c != '\\' && c != quote {
+            continue;
+        }
+        let escape = c.escape_debug();
+        // `escape_debug` escapes both quotes
+        if (escape.len() == 1 || c == '"' || c == '\'') && c != quote {
+            continue;
+        }
+        w.write_str(unsafe { s.get_unchecked(direct..i) })?;
+        direct = i + c.len_utf8();
+        for e in escape {
+            w.write_char(e)?;
+        }
+    }
+    w.write_str(unsafe { s.get_unchecked(direct..) })
+}
+
+impl WriteTo for Debug<str> {
+    const MIN_SIZE: usize = 2;
+
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str("\"")?;
+        write_escaped(w, &self.0, '"')?;
+        w.write_str("\"")
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.len() + 2
+    }
+}
+
+impl WriteTo for Debug<char> {
+    const MIN_SIZE: usize = 3;
+
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str("'")?;
+        write_escaped(w, self.0.encode_utf8(&mut [0; 4]), '\'')?;
+        w.write_str("'")
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.len_utf8() + 2
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteTo for Debug<alloc::string::String> {
+    const MIN_SIZE: usize = 2;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.0.as_str().fmt_debug().write_to(w)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.len() + 2
+    }
+}
+
+impl WriteTo for Debug<bool> {
+    const MIN_SIZE: usize = 4;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(if self.0 { "true" } else { "false" })
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        if self.0 { 4 } else { 5 }
+    }
+}
+
+impl<T> WriteTo for Debug<&T>
+where
+    T: FmtDebug + ?Sized,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.0.fmt_debug().write_to(w)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.0.fmt_debug().len_hint()
+    }
+}
+
+impl<T> WriteTo for Debug<Option<T>>
+where
+    T: FmtDebug,
+{
+    const MIN_SIZE: usize = 4;
+
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match &self.0 {
+            Some(value) => crate::fmt! { (? w) => "Some(" {value;?} ")" },
+            None => w.write_str("None"),
+        }
+    }
+}
+
+impl<T, E> WriteTo for Debug<Result<T, E>>
+where
+    T: FmtDebug,
+    E: FmtDebug,
+{
+    const MIN_SIZE: usize = 4;
+
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match &self.0 {
+            Ok(value) => crate::fmt! { (? w) => "Ok(" {value;?} ")" },
+            Err(err) => crate::fmt! { (? w) => "Err(" {err;?} ")" },
+        }
+    }
+}
+
+impl<T, const N: usize> WriteTo for Debug<[T; N]>
+where
+    T: FmtDebug,
+{
+    const MIN_SIZE: usize = 2;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.0.as_slice().fmt_debug().write_to(w)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> WriteTo for Debug<alloc::vec::Vec<T>>
+where
+    T: FmtDebug,
+{
+    const MIN_SIZE: usize = 2;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.0.as_slice().fmt_debug().write_to(w)
+    }
+}
+
+impl WriteTo for Debug<()> {
+    const MIN_SIZE: usize = 2;
+
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str("()")
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        2
+    }
+}
+
+macro_rules! impl_debug_for_tuple_internal {
+    ($(($($T:ident $i:tt),+))*) => {
+        $(
+            impl<$($T),+> WriteTo for Debug<($($T,)+)>
+            where
+                $($T: FmtDebug,)+
+            {
+                const MIN_SIZE: usize = 2;
+
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    w.write_str("(")?;
+                    $(
+                        if $i > 0 {
+                            w.write_str(", ")?;
+                        }
+                        self.0.$i.fmt_debug().write_to(w)?;
+                    )+
+                    // a tuple with one element needs a trailing comma
+                    w.write_str(if [$($i),+].len() == 1 { ",)" } else { ")" })
+                }
+            }
+        )*
+    };
+}
+
+impl_debug_for_tuple_internal! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    #[test]
+    fn debug() {
+        for s in [
+            "plain",
+            "quote \" and ' and \\",
+            "line\nbreak\ttab\r\0",
+            "\x1B[1m\x7F\u{85}",
+            "e\u{301} \u{200B} \u{FEFF} 日本",
+            "\u{10FFFF}\u{E000}",
+        ] {
+            assert_eq!(crate::fmt! { { str } => {s;?} }, format!("{s:?}"));
+            let string = String::from(s);
+            assert_eq!(crate::fmt! { { str } => {string;?} }, format!("{s:?}"));
+            for c in s.chars() {
+                assert_eq!(crate::fmt! { { str } => {c;?} }, format!("{c:?}"));
+            }
+        }
+
+        let value = (
+            Some("a\"b"),
+            None::<u8>,
+            Ok::<_, char>([1.5, 2.0]),
+            Err::<(), _>('\''),
+            (true,),
+            (),
+        );
+        assert_eq!(crate::fmt! { { str } => {value;?} }, format!("{value:?}"));
+        let list = ["x", "y\n"];
+        assert_eq!(crate::fmt! { { str } => {list;?} }, format!("{list:?}"));
+    }
+}
@@ -0,0 +1,578 @@
+use crate::{
+    write::Write,
+    write_to::{Debug, Precision, WriteTo, int::write_zeros},
+};
+
+/// Options of [`Float`], combine them with `|`
+pub mod options {
+    /// Write a `+` in front of non-negative numbers
+    pub const PLUS: u32 = 1 << 8;
+    /// Scientific notation with a lowercase `e`, like `{:e}` in [`core::fmt`]
+    pub const EXP: u32 = 1 << 9;
+    /// Scientific notation with an uppercase `E`, like `{:E}` in [`core::fmt`]
+    pub const UPPER_EXP: u32 = 1 << 10;
+    /// Like `{:?}` in [`core::fmt`], always write a decimal point and use scientific notation for very small and large numbers
+    pub const DEBUG: u32 = 1 << 11;
+
+    pub(crate) const FIXED: u32 = 1 << 12;
+    pub(crate) const PRECISION_MASK: u32 = 0xFF;
+
+    /// Round to `precision` decimals (or digits after the point in scientific notation) with round half to even
+    ///
+    /// Without it, the shortest output that parses back to the same number is written.
+    #[inline]
+    #[must_use]
+    pub const fn precision(precision: u8) -> u32 {
+        FIXED | precision as u32
+    }
+}
+
+crate::declare_fmt_wrapper_struct! {
+    Float<const OPTIONS: u32> FmtFloat fmt_float,
+}
+
+/// `m * 5^1076` of the bound below the smallest subnormal [`f64`] has 769 digits
+const MAX_DIGITS: usize = 800;
+const LIMBS: usize = 84;
+
+/// Unsigned integer that is large enough for the exact value of any [`f64`] (and its rounding bounds) times `10^1076`
+struct BigInt {
+    /// little endian
+    limbs: [u32; LIMBS],
+    len: usize,
+}
+
+impl BigInt {
+    fn new(n: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = n as u32;
+        limbs[1] = (n >> 32) as u32;
+        let mut this = Self { limbs, len: 2 };
+        this.trim();
+        this
+    }
+
+    fn trim(&mut self) {
+        while self.len > 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    fn mul_small(&mut self, n: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs[..self.len] {
+            let product = u64::from(*limb) * u64::from(n) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    fn mul_pow5(&mut self, mut exp: u32) {
+        const POW5_13: u32 = 1_220_703_125;
+        while exp >= 13 {
+            self.mul_small(POW5_13);
+            exp -= 13;
+        }
+        self.mul_small(5u32.pow(exp));
+    }
+
+    fn shl(&mut self, bits: u32) {
+        let limbs = (bits / 32) as usize;
+        let bits = bits % 32;
+        if bits > 0 {
+            let mut carry = 0;
+            for limb in &mut self.limbs[..self.len] {
+                let shifted = (*limb << bits) | carry;
+                carry = *limb >> (32 - bits);
+                *limb = shifted;
+            }
+            if carry > 0 {
+                self.limbs[self.len] = carry;
+                self.len += 1;
+            }
+        }
+        if limbs > 0 && self.len > 0 {
+            self.limbs.copy_within(..self.len, limbs);
+            self.limbs[..limbs].fill(0);
+            self.len += limbs;
+        }
+    }
+
+    /// Divide by `n` and return the remainder
+    fn div_rem_small(&mut self, n: u32) -> u32 {
+        let mut rem = 0;
+        for limb in self.limbs[..self.len].iter_mut().rev() {
+            let value = (rem << 32) | u64::from(*limb);
+            *limb = (value / u64::from(n)) as u32;
+            rem = value % u64::from(n);
+        }
+        self.trim();
+        rem as u32
+    }
+}
+
+/// `mantissa * 2^exp` of the bits of a finite, non-negative float
+fn decode(bits: u64, fraction_bits: u32, min_exp: i32) -> (u64, i32) {
+    let biased_exp = (bits >> fraction_bits) as i32;
+    let fraction = bits & ((1 << fraction_bits) - 1);
+    if biased_exp == 0 {
+        (fraction, min_exp)
+    } else {
+        (fraction | (1 << fraction_bits), biased_exp - 1 + min_exp)
+    }
+}
+
+/// Exact value of a finite, non-negative float: `0.{digits} * 10^exp`
+///
+/// Zero is `0.0 * 10^1`, so it's written like any other number.
+struct Decimal {
+    /// ascii digits without trailing zeros, the first digit is only zero for zero
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    exp: isize,
+}
+
+impl Decimal {
+    const fn zero() -> Self {
+        let mut digits = [0; MAX_DIGITS];
+        digits[0] = b'0';
+        Self {
+            digits,
+            len: 1,
+            exp: 1,
+        }
+    }
+
+    fn new(value: f64) -> Self {
+        debug_assert!(value.is_finite() && value.is_sign_positive());
+        let (mantissa, exp) = decode(value.to_bits(), 52, -1074);
+        Self::from_parts(mantissa, exp)
+    }
+
+    /// Exact value of `mantissa * 2^exp`
+    fn from_parts(mantissa: u64, exp: i32) -> Self {
+        if mantissa == 0 {
+            return Self::zero();
+        }
+
+        // m * 2^e = m * 5^-e / 10^-e
+        let mut big = BigInt::new(mantissa);
+        if exp >= 0 {
+            big.shl(exp as u32);
+        } else {
+            big.mul_pow5(exp.unsigned_abs());
+        }
+
+        // 9 decimal digits at a time, least significant first
+        let mut chunks = [0u32; MAX_DIGITS / 9 + 1];
+        let mut chunk_count = 0;
+        while big.len > 0 {
+            chunks[chunk_count] = big.div_rem_small(1_000_000_000);
+            chunk_count += 1;
+        }
+
+        let mut this = Self::zero();
+        this.len = 0;
+        for (i, &chunk) in chunks[..chunk_count].iter().rev().enumerate() {
+            let mut chunk_digits = [b'0'; 9];
+            let mut chunk = chunk;
+            for digit in chunk_digits.iter_mut().rev() {
+                *digit = b'0' + (chunk % 10) as u8;
+                chunk /= 10;
+            }
+            let skip = if i == 0 {
+                chunk_digits.iter().take_while(|&&d| d == b'0').count()
+            } else {
+                0
+            };
+            let new_len = this.len + 9 - skip;
+            this.digits[this.len..new_len].copy_from_slice(&chunk_digits[skip..]);
+            this.len = new_len;
+        }
+        this.exp = this.len as isize + exp.min(0) as isize;
+        this.trim();
+        this
+    }
+
+    fn trim(&mut self) {
+        while self.len > 0 && self.digits[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+        if self.len == 0 {
+            *self = Self::zero();
+        }
+    }
+
+    /// Whether rounding to `n` digits with round half to even rounds up
+    fn rounds_up(&self, n: usize) -> bool {
+        match self.digits[n].cmp(&b'5') {
+            core::cmp::Ordering::Greater => true,
+            core::cmp::Ordering::Less => false,
+            core::cmp::Ordering::Equal => {
+                self.len > n + 1 || (n > 0 && (self.digits[n - 1] - b'0') % 2 == 1)
+            }
+        }
+    }
+
+    /// Cut off after `n` significant digits, the last digit is incremented if `up`
+    fn cut(&mut self, n: usize, up: bool) {
+        if n >= self.len {
+            return;
+        }
+        self.len = n;
+        if up {
+            let mut i = n;
+            loop {
+                if i == 0 {
+                    self.digits[0] = b'1';
+                    self.len = 1;
+                    self.exp += 1;
+                    break;
+                }
+                i -= 1;
+                if self.digits[i] == b'9' {
+                    self.len = i;
+                } else {
+                    self.digits[i] += 1;
+                    self.len = i + 1;
+                    break;
+                }
+            }
+        }
+        self.trim();
+    }
+
+    /// Round to `n` significant digits with round half to even, negative `n` rounds to zero
+    fn round(&mut self, n: isize) {
+        if n < 0 {
+            *self = Self::zero();
+        } else if (n as usize) < self.len {
+            let up = self.rounds_up(n as usize);
+            self.cut(n as usize, up);
+        }
+    }
+
+    /// Shortest digits that parse back to the float `mantissa * 2^exp`, the nearest to its value if there are several
+    ///
+    /// This is the free-format algorithm of Steele & White (like Dragon4), on the exact decimal values
+    /// of the float and the midpoints to its neighbours instead of on big integer fractions.
+    /// `lower_closer` is for powers of two, where the next smaller float is only half as far away.
+    fn shortest(mantissa: u64, exp: i32, lower_closer: bool) -> Self {
+        if mantissa == 0 {
+            return Self::zero();
+        }
+        let mut value = Self::from_parts(mantissa, exp);
+        let low = if lower_closer {
+            Self::from_parts(4 * mantissa - 1, exp - 2)
+        } else {
+            Self::from_parts(2 * mantissa - 1, exp - 1)
+        };
+        let high = Self::from_parts(2 * mantissa + 1, exp - 1);
+        // the midpoints are parsed to the float with the even mantissa
+        let inclusive = mantissa.is_multiple_of(2);
+
+        // `value - low` and `high - value`, both cut off after the current digit,
+        // in units of the current digit and only counted up to 2
+        let mut low_gap = 0;
+        let mut high_gap = 0;
+        let mut power = high.exp - 1;
+        loop {
+            let digit = value.digit_at(power);
+            low_gap = (low_gap * 10 + digit - low.digit_at(power)).min(2);
+            high_gap = (high_gap * 10 + high.digit_at(power) - digit).min(2);
+            // whether the digits so far, or with the last one incremented, are within the bounds
+            let low_ok = low_gap >= 1 || (inclusive && low.last_power() >= power);
+            let high_ok =
+                high_gap >= 2 || (high_gap == 1 && (inclusive || high.last_power() < power));
+            if low_ok || high_ok {
+                // ties round up like core::fmt
+                let up = high_ok && (!low_ok || value.digit_at(power - 1) >= 5);
+                value.cut((value.exp - power).max(0) as usize, up);
+                return value;
+            }
+            power -= 1;
+        }
+    }
+
+    /// The digit for `10^power`
+    #[inline]
+    fn digit_at(&self, power: isize) -> i32 {
+        let i = self.exp - 1 - power;
+        if i >= 0 && (i as usize) < self.len {
+            i32::from(self.digits[i as usize] - b'0')
+        } else {
+            0
+        }
+    }
+
+    /// The power of ten of the last digit
+    #[inline]
+    const fn last_power(&self) -> isize {
+        self.exp - self.len as isize
+    }
+
+    #[inline]
+    fn digit_str(&self, start: usize, end: usize) -> &str {
+        // only ascii digits are written
+        unsafe { core::str::from_utf8_unchecked(&self.digits[start..end]) }
+    }
+
+    /// Write the digits from index `start` to `end`, with zeros outside of the digits
+    fn write_digits<W>(&self, w: &mut W, start: isize, end: isize) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        let len = self.len as isize;
+        let digits_start = start.clamp(0, len);
+        let digits_end = end.clamp(digits_start, len);
+        write_zeros(w, (digits_start - start).min(end - start).max(0) as usize)?;
+        w.write_str(self.digit_str(digits_start as usize, digits_end as usize))?;
+        write_zeros(w, (end - digits_end.max(start)).max(0) as usize)
+    }
+}
+
+/// `bits` are the bits of `value` in its own type, with `fraction_bits` and `min_exp` of that type for [`decode`]
+fn write_float<W>(
+    w: &mut W,
+    value: f64,
+    (bits, fraction_bits, min_exp): (u64, u32, i32),
+    debug_exp: bool,
+    options: u32,
+) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+{
+    if value.is_nan() {
+        return w.write_str("NaN");
+    }
+    if value.is_sign_negative() {
+        w.write_str("-")?;
+    } else if options & options::PLUS != 0 {
+        w.write_str("+")?;
+    }
+    if value.is_infinite() {
+        return w.write_str("inf");
+    }
+
+    let abs = value.abs();
+    let fixed = options & options::FIXED != 0;
+    let precision = (options & options::PRECISION_MASK) as isize;
+    let debug = options & options::DEBUG != 0;
+    let exp_form =
+        options & (options::EXP | options::UPPER_EXP) != 0 || (debug && !fixed && debug_exp);
+
+    let decimal = if fixed {
+        let mut decimal = Decimal::new(abs);
+        decimal.round(if exp_form {
+            precision + 1
+        } else {
+            decimal.exp + precision
+        });
+        decimal
+    } else {
+        let (mantissa, exp) = decode(bits, fraction_bits, min_exp);
+        Decimal::shortest(mantissa, exp, mantissa == 1 << fraction_bits && exp > min_exp)
+    };
+
+    if exp_form {
+        let fraction = if fixed {
+            precision
+        } else {
+            decimal.len as isize - 1
+        };
+        decimal.write_digits(w, 0, 1)?;
+        if fraction > 0 {
+            w.write_str(".")?;
+            decimal.write_digits(w, 1, 1 + fraction)?;
+        }
+        w.write_str(if options & options::UPPER_EXP != 0 {
+            "E"
+        } else {
+            "e"
+        })?;
+        (decimal.exp - 1).write_to(w)
+    } else {
+        let fraction = if fixed {
+            precision
+        } else {
+            (decimal.len as isize - decimal.exp).max(debug as isize)
+        };
+        decimal.write_digits(w, 0.min(decimal.exp - 1), decimal.exp)?;
+        if fraction > 0 {
+            w.write_str(".")?;
+            decimal.write_digits(w, decimal.exp, decimal.exp + fraction)?;
+        }
+        Ok(())
+    }
+}
+
+/// The float types, so the options can also be given at runtime
+trait FloatInternal: Copy {
+    fn write_with<W>(self, w: &mut W, options: u32) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized;
+}
+
+macro_rules! impl_float_internal {
+    ($($t:ty => $fraction_bits:literal $min_exp:literal),* $(,)?) => {
+        $(
+            impl FloatInternal for $t {
+                fn write_with<W>(self, w: &mut W, options: u32) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    write_float(
+                        w,
+                        self.into(),
+                        (self.abs().to_bits().into(), $fraction_bits, $min_exp),
+                        self != 0.0 && !(1e-4..1e16).contains(&self.abs()),
+                        options,
+                    )
+                }
+            }
+
+            impl<const OPTIONS: u32> WriteTo for Float<$t, OPTIONS> {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    self.0.write_with(w, OPTIONS)
+                }
+            }
+
+            impl WriteTo for $t {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    self.write_with(w, 0)
+                }
+            }
+
+            impl WriteTo for Debug<$t> {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    self.0.write_with(w, options::DEBUG)
+                }
+            }
+
+            impl<const PRECISION: u8> WriteTo for Precision<$t, PRECISION> {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    self.0.write_with(w, options::precision(PRECISION))
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    // at least the integer digit, the point and the decimals
+                    if PRECISION == 0 { 1 } else { PRECISION as usize + 2 }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_internal! {
+    f32 => 23 -149,
+    f64 => 52 -1074,
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    #[test]
+    fn float() {
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            0.3,
+            2.5,
+            0.125,
+            1.0 / 3.0,
+            123456.789,
+            1e-7,
+            1e15,
+            1e16,
+            1.5e300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::EPSILON,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        for n in values {
+            assert_eq!(crate::fmt! { { str } => {n} }, format!("{n}"));
+            assert_eq!(crate::fmt! { { str } => {n; ?} }, format!("{n:?}"));
+            assert_eq!(
+                crate::fmt! { { str } => {n; .0} " " {n; .3} },
+                format!("{n:.0} {n:.3}")
+            );
+            assert_eq!(
+                crate::fmt! { { str } => {n; e} " " {n; E .2} },
+                format!("{n:e} {n:.2E}")
+            );
+            let f = n as f32;
+            assert_eq!(
+                crate::fmt! { { str } => {f} " " {f; ?} " " {f; .2} " " {f; e} },
+                format!("{f} {f:?} {f:.2} {f:e}")
+            );
+        }
+        // round half to even on the exact value
+        assert_eq!(
+            crate::fmt! { { str } => {0.5; .0} {1.5; .0} {2.5; .0} " " {0.125; .2} " " {0.375; .2} },
+            "022 0.12 0.38"
+        );
+        assert_eq!(
+            crate::fmt! { { str } => {2.0; e plus} " " {9.99; .1} },
+            "+2e0 10.0"
+        );
+
+        let mut x = 1.0f64;
+        for _ in 0..2000 {
+            x = x * 1.37 + 0.001;
+            let y = 1.0 / x;
+            assert_eq!(
+                crate::fmt! { { str } => {x} " " {y; ?} " " {x; .5} },
+                format!("{x} {y:?} {x:.5}")
+            );
+        }
+
+        // shortest round trip on random bits
+        let mut bits = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..5000 {
+            bits ^= bits << 13;
+            bits ^= bits >> 7;
+            bits ^= bits << 17;
+            let x = f64::from_bits(bits);
+            let y = f32::from_bits(bits as u32);
+            assert_eq!(
+                crate::fmt! { { str } => {x} " " {x; e} " " {y} " " {y; ?} },
+                format!("{x} {x:e} {y} {y:?}")
+            );
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use crate::{
+    write::Write,
+    write_to::{Binary, Debug, Hex, Octal, WriteTo},
+};
+
+/// Options of [`Int`], combine them with `|`
+pub mod options {
+    /// Write a `+` in front of non-negative numbers
+    pub const PLUS: u32 = 1 << 8;
+    /// Lowercase hex digits
+    pub const LOWER: u32 = 1 << 9;
+    /// Write `0b`, `0o` or `0x` in front of the digits, decimal has no prefix
+    pub const PREFIX: u32 = 1 << 10;
+    /// Fill with zeros to the number of digits of the largest value of the type, like `0x00FF` for a [`u16`]
+    pub const FULL: u32 = 1 << 11;
+    /// Separate groups of digits with a space instead of `_`
+    pub const SPACE: u32 = 1 << 16;
+
+    pub(crate) const WIDTH_MASK: u32 = 0xFF;
+    pub(crate) const GROUP_SHIFT: u32 = 12;
+    pub(crate) const GROUP_MASK: u32 = 0xF << GROUP_SHIFT;
+
+    /// Fill with zeros after the sign and prefix, until the output is at least `width` bytes long
+    /// (like `{:05}` in [`core::fmt`]), separators aren't counted
+    #[inline]
+    #[must_use]
+    pub const fn zero(width: u8) -> u32 {
+        width as u32
+    }
+
+    /// Separate every `digits` digits from the right with `_` (or a space with [`SPACE`]), like `0xDEAD_BEEF`
+    ///
+    /// # Panics
+    /// If `digits` is larger than 15
+    #[inline]
+    #[must_use]
+    pub const fn group(digits: u8) -> u32 {
+        assert!(digits <= 15, "groups can be at most 15 digits");
+        (digits as u32) << GROUP_SHIFT
+    }
+}
+
+crate::declare_fmt_wrapper_struct! {
+    Int<const RADIX: u8, const OPTIONS: u32> FmtInt fmt_int,
+}
+
+/// Enough for the binary digits of a [`u128`]
+const MAX_DIGITS: usize = 128;
+
+/// Digits of a number, written from the back of the buffer
+struct Digits {
+    buf: [u8; MAX_DIGITS],
+    start: usize,
+}
+
+impl Digits {
+    #[inline]
+    fn as_str(&self) -> &str {
+        // only ascii digits are written
+        unsafe { core::str::from_utf8_unchecked(self.buf.get_unchecked(self.start..)) }
+    }
+}
+
+/// The unsigned types that the digits are calculated in
+trait Magnitude: Copy {
+    fn digits(self, radix: u8, lower: bool) -> Digits;
+    fn digit_count(self, radix: u8) -> usize;
+}
+
+macro_rules! impl_magnitude_internal {
+    ($($u:ty)*) => {
+        $(
+            impl Magnitude for $u {
+                fn digits(mut self, radix: u8, lower: bool) -> Digits {
+                    debug_assert!(matches!(radix, 2 | 8 | 10 | 16), "invalid radix");
+                    let chars = if lower { b"0123456789abcdef" } else { b"0123456789ABCDEF" };
+                    let radix = radix as $u;
+                    let mut buf = [0; MAX_DIGITS];
+                    let mut start = MAX_DIGITS;
+                    loop {
+                        start -= 1;
+                        buf[start] = chars[(self % radix) as usize];
+                        self /= radix;
+                        if self == 0 {
+                            break;
+                        }
+                    }
+                    Digits { buf, start }
+                }
+
+                #[inline]
+                fn digit_count(self, radix: u8) -> usize {
+                    match self.checked_ilog(radix as $u) {
+                        Some(log) => log as usize + 1,
+                        None => 1,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_magnitude_internal! { u64 u128 }
+
+/// Where the digits go in the output
+struct Layout {
+    sign: &'static str,
+    prefix: &'static str,
+    /// zeros in front of the digits
+    zeros: usize,
+    group: usize,
+    separator: &'static str,
+}
+
+impl Layout {
+    fn new(
+        negative: bool,
+        radix: u8,
+        digit_count: usize,
+        full_digits: usize,
+        options: u32,
+    ) -> Self {
+        let sign = if negative {
+            "-"
+        } else if options & options::PLUS != 0 {
+            "+"
+        } else {
+            ""
+        };
+        let prefix = if options & options::PREFIX == 0 {
+            ""
+        } else {
+            match radix {
+                2 => "0b",
+                8 => "0o",
+                16 => "0x",
+                _ => "",
+            }
+        };
+        let mut total_digits = digit_count;
+        if options & options::FULL != 0 {
+            total_digits = total_digits.max(full_digits);
+        }
+        let width = (options & options::WIDTH_MASK) as usize;
+        total_digits = total_digits.max(width.saturating_sub(sign.len() + prefix.len()));
+        Self {
+            sign,
+            prefix,
+            zeros: total_digits - digit_count,
+            group: ((options & options::GROUP_MASK) >> options::GROUP_SHIFT) as usize,
+            separator: if options & options::SPACE == 0 {
+                "_"
+            } else {
+                " "
+            },
+        }
+    }
+
+    fn len(&self, digit_count: usize) -> usize {
+        let total_digits = self.zeros + digit_count;
+        let separators = (total_digits - 1).checked_div(self.group).unwrap_or(0);
+        self.sign.len() + self.prefix.len() + total_digits + separators
+    }
+
+    fn write<W>(&self, w: &mut W, digits: &str) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(self.sign)?;
+        w.write_str(self.prefix)?;
+        if self.group == 0 {
+            write_zeros(w, self.zeros)?;
+            return w.write_str(digits);
+        }
+
+        let total_digits = self.zeros + digits.len();
+        // the first group is the one that may be shorter
+        let mut end = match total_digits % self.group {
+            0 => self.group,
+            first => first,
+        };
+        let mut start = 0;
+        while start < total_digits {
+            if start > 0 {
+                w.write_str(self.separator)?;
+            }
+            write_zeros(w, end.min(self.zeros).saturating_sub(start))?;
+            w.write_str(
+                &digits[start.max(self.zeros) - self.zeros..end.max(self.zeros) - self.zeros],
+            )?;
+            start = end;
+            end += self.group;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn write_zeros<W>(w: &mut W, mut count: usize) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+{
+    const ZEROS: &str = "0000000000000000";
+    while count > ZEROS.len() {
+        w.write_str(ZEROS)?;
+        count -= ZEROS.len();
+    }
+    w.write_str(&ZEROS[..count])
+}
+
+/// Only decimal has a sign, the other radixes write the two's complement like [`core::fmt`]
+macro_rules! signed_internal {
+    ($n:ident $radix:ident $u:ty) => {
+        if $radix == 10 {
+            ($n < 0, $n.unsigned_abs())
+        } else {
+            (false, $n as $u)
+        }
+    };
+}
+
+macro_rules! impl_int_internal {
+    ($($t:ty => $u:ty, $unsigned:ty, |$n:ident| $split:expr;)*) => {
+        $(
+            impl<const RADIX: u8, const OPTIONS: u32> WriteTo for Int<$t, RADIX, OPTIONS> {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    let $n = self.0;
+                    let (negative, magnitude): (bool, _) = $split;
+                    let digits = (magnitude as $u).digits(RADIX, OPTIONS & options::LOWER != 0);
+                    let digits = digits.as_str();
+                    let full_digits = (<$unsigned>::MAX as $u).digit_count(RADIX);
+                    Layout::new(negative, RADIX, digits.len(), full_digits, OPTIONS).write(w, digits)
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    let $n = self.0;
+                    let (negative, magnitude): (bool, _) = $split;
+                    let digit_count = (magnitude as $u).digit_count(RADIX);
+                    let full_digits = (<$unsigned>::MAX as $u).digit_count(RADIX);
+                    Layout::new(negative, RADIX, digit_count, full_digits, OPTIONS).len(digit_count)
+                }
+            }
+
+            impl WriteTo for $t {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    FmtInt::<10, 0>::fmt_int(self).write_to(w)
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    FmtInt::<10, 0>::fmt_int(self).len_hint()
+                }
+            }
+
+            impl_int_internal! { @radix $t => Debug 10, Binary 2, Octal 8, Hex 16 }
+        )*
+    };
+    (@radix $t:ty => $($Struct:ident $radix:literal),*) => {
+        $(
+            impl WriteTo for $Struct<$t> {
+                const MIN_SIZE: usize = 1;
+
+                #[inline]
+                fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+                where
+                    W: Write + ?Sized,
+                {
+                    FmtInt::<$radix, 0>::fmt_int(&self.0).write_to(w)
+                }
+
+                #[inline]
+                fn len_hint(&self) -> usize {
+                    FmtInt::<$radix, 0>::fmt_int(&self.0).len_hint()
+                }
+            }
+        )*
+    };
+}
+
+impl_int_internal! {
+    u8 => u64, u8, |n| (false, n);
+    u16 => u64, u16, |n| (false, n);
+    u32 => u64, u32, |n| (false, n);
+    u64 => u64, u64, |n| (false, n);
+    usize => u64, usize, |n| (false, n);
+    u128 => u128, u128, |n| (false, n);
+    i8 => u64, u8, |n| signed_internal!(n RADIX u8);
+    i16 => u64, u16, |n| signed_internal!(n RADIX u16);
+    i32 => u64, u32, |n| signed_internal!(n RADIX u32);
+    i64 => u64, u64, |n| signed_internal!(n RADIX u64);
+    isize => u64, usize, |n| signed_internal!(n RADIX usize);
+    i128 => u128, u128, |n| signed_internal!(n RADIX u128);
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{FmtInt, options};
+    use crate::write_to::WriteTo;
+
+    fn check(s: &str, value: &(impl WriteTo + ?Sized)) {
+        assert_eq!(value.len_hint(), s.len());
+        assert_eq!(crate::fmt! { { str } => {value} }, s);
+    }
+
+    #[test]
+    fn int() {
+        for n in [0, 1, -1, 9, 10, -99, 100, 12345, i64::MIN, i64::MAX] {
+            check(&format!("{n}"), &n);
+            check(&format!("{n:b}"), FmtInt::<2, 0>::fmt_int(&n));
+            check(
+                &format!("{:o}", n as u64),
+                FmtInt::<8, 0>::fmt_int(&(n as u64)),
+            );
+            check(
+                &format!("{:X}", n as u32),
+                FmtInt::<16, 0>::fmt_int(&(n as u32)),
+            );
+            check(
+                &format!("{n:+06}"),
+                FmtInt::<10, { options::PLUS | options::zero(6) }>::fmt_int(&n),
+            );
+        }
+        check(&u128::MAX.to_string(), &u128::MAX);
+        check(
+            &format!("{:b}", u128::MAX),
+            FmtInt::<2, 0>::fmt_int(&u128::MAX),
+        );
+        check(&i128::MIN.to_string(), &i128::MIN);
+        check("ff", FmtInt::<16, { options::LOWER }>::fmt_int(&255u8));
+
+        let n = -42i16;
+        assert_eq!(
+            crate::fmt! { { str } => {n; d zero 6} " " {n; h} " " {255u8; h lower} " " {8; o} " " {5; b zero 4} },
+            "-00042 FFD6 ff 10 0101"
+        );
+        assert_eq!(
+            crate::fmt! { { str } => {7u8; d plus} " " {n; ?} },
+            "+7 -42"
+        );
+    }
+
+    #[test]
+    fn layout() {
+        check(
+            "0b1010_0101",
+            FmtInt::<2, { options::PREFIX | options::group(4) }>::fmt_int(&0xA5u8),
+        );
+        check(
+            "0x0000_00FF",
+            FmtInt::<16, { options::PREFIX | options::FULL | options::group(4) }>::fmt_int(&255u32),
+        );
+        check(
+            "-1 000 000",
+            FmtInt::<10, { options::group(3) | options::SPACE }>::fmt_int(&-1_000_000),
+        );
+        check(
+            &format!("{:#010x}", 255),
+            FmtInt::<16, { options::LOWER | options::PREFIX | options::zero(10) }>::fmt_int(&255),
+        );
+
+        let reg = 0xDEAD_BEEFu32;
+        assert_eq!(
+            crate::fmt! { { str } => {reg; h prefix group4} " " {reg; h lower prefix group4} " " {1u16; b full group8} " " {8u8; o prefix full} },
+            "0xDEAD_BEEF 0xdead_beef 00000000_00000001 0o010"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    write::Write,
+    write_to::{Iterator, WriteTo},
+};
+
+/// Human readable list like `a, b and c`, `a, b, or c` or `a, b and 3 more`, like `{x; list or oxford}` in [`fmt!`](crate::fmt)
+///
+/// The [`Iterator`] wrapper (`{x; ..}`) with separators between the elements.
+/// Two elements are only separated by the conjunction, like `a and b`.
+pub struct List<'s, I>
+where
+    Iterator<I>: WriteTo,
+{
+    iter: Iterator<I>,
+    separator: &'s str,
+    conjunction: &'s str,
+    oxford: bool,
+    max: usize,
+    more: &'s str,
+}
+
+impl<I> List<'static, I>
+where
+    Iterator<I>: WriteTo,
+{
+    /// `a, b and c`
+    #[inline]
+    pub const fn new(iter: I) -> Self {
+        Self {
+            iter: Iterator(iter),
+            separator: ", ",
+            conjunction: "and",
+            oxford: false,
+            max: usize::MAX,
+            more: " more",
+        }
+    }
+}
+
+impl<'s, I> List<'s, I>
+where
+    Iterator<I>: WriteTo,
+{
+    /// `a, b and c`
+    #[inline]
+    #[must_use]
+    pub const fn and(self) -> Self {
+        self.with_conjunction("and")
+    }
+
+    /// `a, b or c`
+    #[inline]
+    #[must_use]
+    pub const fn or(self) -> Self {
+        self.with_conjunction("or")
+    }
+
+    /// The word before the last element, with spaces around it
+    #[inline]
+    #[must_use]
+    pub const fn with_conjunction(mut self, conjunction: &'s str) -> Self {
+        self.conjunction = conjunction;
+        self
+    }
+
+    /// Separator between the elements except the last two, `", "` by default
+    #[inline]
+    #[must_use]
+    pub const fn with_separator(mut self, separator: &'s str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Oxford comma before the conjunction if there are more than two elements, like `a, b, and c`
+    #[inline]
+    #[must_use]
+    pub const fn oxford(mut self) -> Self {
+        self.oxford = true;
+        self
+    }
+
+    /// Write at most `max` elements, the rest is counted like `a, b and 3 more`
+    #[inline]
+    #[must_use]
+    pub const fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Text after the number of elements that weren't written, `" more"` by default
+    #[inline]
+    #[must_use]
+    pub const fn with_more(mut self, more: &'s str) -> Self {
+        self.more = more;
+        self
+    }
+
+    fn write_separator<W>(&self, w: &mut W, index: usize, is_last: bool) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        if index == 0 {
+            Ok(())
+        } else if !is_last {
+            w.write_str(self.separator)
+        } else {
+            if self.oxford && index > 1 {
+                w.write_str(self.separator.trim_end())?;
+            }
+            w.write_str(" ")?;
+            w.write_str(self.conjunction)?;
+            w.write_str(" ")
+        }
+    }
+}
+
+impl<'t, I, T> WriteTo for List<'_, I>
+where
+    I: core::iter::Iterator<Item = &'t T> + Clone,
+    T: WriteTo + ?Sized + 't,
+{
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        let mut iter = self.iter.0.clone();
+        let Some(mut t) = iter.next() else {
+            return Ok(());
+        };
+        // one element of lookahead to know which separator comes before `t`
+        for index in 0.. {
+            let next = iter.next();
+            if index == self.max {
+                let more = 1 + next.map_or(0, |_| 1 + iter.count());
+                self.write_separator(w, index, true)?;
+                more.write_to(w)?;
+                return w.write_str(self.more);
+            }
+            self.write_separator(w, index, next.is_none())?;
+            t.write_to(w)?;
+            match next {
+                Some(next) => t = next,
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// Sum of the hints of the elements that are written
+    #[inline]
+    fn len_hint(&self) -> usize {
+        self.iter.0.clone().take(self.max).map(T::len_hint).sum()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::List;
+    use crate::write_to::WriteTo;
+
+    #[test]
+    fn list() {
+        let options = ["json", "yaml", "toml"];
+        assert_eq!(
+            crate::fmt! { { str } => {options; list} "|" {options; list or oxford} "|" {options; list max 1} },
+            "json, yaml and toml|json, yaml, or toml|json and 2 more"
+        );
+        let two = ["a", "b"];
+        let one = ["a"];
+        let none: [&str; 0] = [];
+        assert_eq!(
+            crate::fmt! { { str } => {two; list or oxford} "|" {one; list} "|" {none; list} "|" {options; list oxford max 2} },
+            "a or b|a||json, yaml, and 1 more"
+        );
+
+        let numbers = List::new([1, 2, 3, 4].iter())
+            .with_conjunction("&")
+            .max(3)
+            .with_more(" others");
+        let words = List::new(["a", "b", "c"].into_iter())
+            .with_separator("; ")
+            .or()
+            .oxford();
+        assert_eq!(
+            crate::fmt! { { str } => {numbers} "|" {words} },
+            "1, 2, 3 & 1 others|a; b; or c"
+        );
+
+        assert_eq!(
+            crate::fmt! { { str } => "expected " @..join(options => ", " => last ", or " pair " or " => |o| "`" {o} "`") },
+            "expected `json`, `yaml`, or `toml`"
+        );
+        assert_eq!(
+            crate::fmt! { { str } => @..join(two => ", " => last " and " => |o| {o}) },
+            "a and b"
+        );
+    }
+
+    #[test]
+    fn len_hint() {
+        let options = ["json", "yaml", "toml"];
+        assert_eq!(List::new(options.iter()).len_hint(), 12);
+        assert_eq!(List::new(options.iter()).max(1).len_hint(), 4);
+
+        struct Expected([&'static str; 3]);
+        impl WriteTo for Expected {
+            crate::fmt! { [s] => "expected " @..join(s.0 => ", " => last ", or " pair " or " => |o| "`" {o} "`") }
+        }
+        let expected = Expected(options);
+        assert_eq!(expected.len_hint(), expected.exact_len());
+    }
+}
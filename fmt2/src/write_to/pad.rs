@@ -0,0 +1,222 @@
+use crate::{
+    utils::char_width,
+    write::{Write, counting::CountingWriter},
+    write_to::{Fmt, WriteTo},
+};
+
+/// Alignment of [`Pad`] and [`PadWidth`], `ALIGN` has to be one of these
+pub mod align {
+    pub const LEFT: char = '<';
+    pub const RIGHT: char = '>';
+    pub const CENTER: char = '^';
+
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(align: char) -> bool {
+        matches!(align, LEFT | RIGHT | CENTER)
+    }
+}
+
+crate::declare_fmt_wrapper_struct! {
+    /// Pad to at least `WIDTH` display columns with `FILL`, like `{:>10}` in [`core::fmt`]
+    ///
+    /// See [`align`] for `ALIGN`, other chars don't compile. The width is measured with [`WriteTo::measure`]
+    /// so nothing is allocated, for left alignment it's counted while writing instead.
+    Pad<const WIDTH: usize, const ALIGN: char, const FILL: char> FmtPad fmt_pad,
+}
+
+impl<T, const WIDTH: usize, const ALIGN: char, const FILL: char> WriteTo
+    for Pad<T, WIDTH, ALIGN, FILL>
+where
+    T: Fmt + ?Sized,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        const { assert!(align::is_valid(ALIGN), "ALIGN has to be one of `align`") };
+        write_padded(w, self.0.fmt(), WIDTH, ALIGN, FILL)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        len_hint(self.0.fmt(), WIDTH)
+    }
+}
+
+/// [`Pad`] with a width that is only known at runtime
+pub struct PadWidth<'t, T, const ALIGN: char, const FILL: char = ' '>
+where
+    T: WriteTo + ?Sized,
+{
+    value: &'t T,
+    width: usize,
+}
+
+impl<'t, T, const ALIGN: char, const FILL: char> PadWidth<'t, T, ALIGN, FILL>
+where
+    T: WriteTo + ?Sized,
+{
+    #[inline]
+    #[must_use]
+    pub const fn new(value: &'t T, width: usize) -> Self {
+        Self { value, width }
+    }
+}
+
+impl<T, const ALIGN: char, const FILL: char> WriteTo for PadWidth<'_, T, ALIGN, FILL>
+where
+    T: WriteTo + ?Sized,
+{
+    #[inline]
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        const { assert!(align::is_valid(ALIGN), "ALIGN has to be one of `align`") };
+        write_padded(w, self.value, self.width, ALIGN, FILL)
+    }
+
+    #[inline]
+    fn len_hint(&self) -> usize {
+        len_hint(self.value, self.width)
+    }
+}
+
+// every column takes at least one byte, so the padded output is at least `width` bytes long
+#[inline]
+fn len_hint<T>(value: &T, width: usize) -> usize
+where
+    T: WriteTo + ?Sized,
+{
+    value.len_hint().max(width)
+}
+
+fn write_padded<W, T>(
+    w: &mut W,
+    value: &T,
+    width: usize,
+    align: char,
+    fill: char,
+) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+    T: WriteTo + ?Sized,
+{
+    let fill_width = char_width(fill).max(1);
+    if align == align::LEFT {
+        let mut counter = CountWidth {
+            writer: w,
+            counter: CountingWriter::new(),
+        };
+        value.write_to(&mut counter)?;
+        let value_width = counter.counter.width;
+        return write_fill(w, fill, width.saturating_sub(value_width) / fill_width);
+    }
+
+    let fill_count = width.saturating_sub(value.measure().width) / fill_width;
+    // `align` is checked at compile time by the callers
+    let (before, after) = if align == align::CENTER {
+        (fill_count / 2, fill_count - fill_count / 2)
+    } else {
+        (fill_count, 0)
+    };
+    write_fill(w, fill, before)?;
+    value.write_to(w)?;
+    write_fill(w, fill, after)
+}
+
+pub(crate) fn write_fill<W>(w: &mut W, fill: char, mut count: usize) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+{
+    if fill.is_ascii() {
+        let chunk = [fill as u8; 16];
+        while count > 0 {
+            let len = count.min(chunk.len());
+            w.write_str(unsafe { core::str::from_utf8_unchecked(&chunk[..len]) })?;
+            count -= len;
+        }
+    } else {
+        for _ in 0..count {
+            w.write_char(fill)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes through and measures the width at the same time
+struct CountWidth<'w, W>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+    counter: CountingWriter,
+}
+
+impl<W> Write for CountWidth<'_, W>
+where
+    W: Write + ?Sized,
+{
+    type Error = W::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let Ok(()) = self.counter.write_str(s);
+        self.writer.write_str(s)
+    }
+}
+
+/// Fill char from the tokens of a literal in [`fmt!`](crate::fmt), so `{x; 0>4}` and `{x; '*'^8}` both work
+#[doc(hidden)]
+#[must_use]
+pub const fn fill_from_literal_internal(literal: &str) -> char {
+    let bytes = literal.as_bytes();
+    let (start, end) = match bytes {
+        [b'\'', .., b'\''] => (1, bytes.len() - 1),
+        [_] => (0, 1),
+        _ => panic!("fill must be a single digit or a char literal"),
+    };
+    let b0 = bytes[start] as u32;
+    let (mut c, len) = match b0 {
+        0x00..=0x7F => (b0, 1),
+        0xC0..=0xDF => (b0 & 0x1F, 2),
+        0xE0..=0xEF => (b0 & 0x0F, 3),
+        _ => (b0 & 0x07, 4),
+    };
+    if start + len != end {
+        panic!("fill must be a single char");
+    }
+    let mut i = start + 1;
+    while i < end {
+        c = (c << 6) | (bytes[i] as u32 & 0x3F);
+        i += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => panic!("fill must be a single char"),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    #[test]
+    fn pad() {
+        let s = "ab";
+        let n = 7;
+        assert_eq!(crate::fmt! { { str } => "[" {s; <5} "]" }, "[ab   ]");
+        assert_eq!(crate::fmt! { { str } => "[" {s; >5} "]" }, "[   ab]");
+        assert_eq!(crate::fmt! { { str } => "[" {s; ^5} "]" }, "[ ab  ]");
+        assert_eq!(crate::fmt! { { str } => "[" {n; 0>4} "]" }, "[0007]");
+        assert_eq!(crate::fmt! { { str } => "[" {s; '·'^6} "]" }, "[··ab··]");
+        assert_eq!(crate::fmt! { { str } => "[" {s; >1} "]" }, "[ab]");
+
+        let width = 4;
+        let red = crate::fmt! { {} => @fg(@red) [{@s}] };
+        assert_eq!(
+            crate::fmt! { { str } => {red; >(width)} },
+            "  \x1B[38;5;9mab\x1B[39m"
+        );
+    }
+}
@@ -0,0 +1,549 @@
+use crate::{
+    write::{
+        Write, WriteInfallible,
+        escape::{self, Escape, Escaper},
+        strip_ansi::StripAnsi,
+        truncate::Truncate,
+    },
+    write_to::{
+        DynWriteTo, WriteTo,
+        pad::{align, write_fill},
+    },
+};
+
+/// Output format of a [`Table`] or [`TableWriter`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Columns aligned with spaces
+    #[default]
+    Plain,
+    /// Columns aligned inside of box-drawing borders
+    Boxed,
+    /// GitHub flavored Markdown table, `|` in cells is escaped and ANSI escape sequences are removed
+    Markdown,
+    /// Comma separated values (RFC 4180), cells are only quoted if needed and ANSI escape sequences are removed
+    Csv,
+    /// Tab separated values, tabs, newlines and backslashes are escaped and ANSI escape sequences are removed
+    Tsv,
+}
+
+impl Style {
+    /// Whether the columns are padded to the same width
+    #[inline]
+    #[must_use]
+    pub const fn is_aligned(self) -> bool {
+        matches!(self, Self::Plain | Self::Boxed | Self::Markdown)
+    }
+}
+
+/// Header and layout of a column of a [`Table`] or [`TableWriter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column<'h> {
+    header: &'h str,
+    align: char,
+    width: Option<usize>,
+    max_width: usize,
+}
+
+impl<'h> Column<'h> {
+    /// Left aligned column without a max width
+    #[inline]
+    #[must_use]
+    pub const fn new(header: &'h str) -> Self {
+        Self {
+            header,
+            align: align::LEFT,
+            width: None,
+            max_width: usize::MAX,
+        }
+    }
+
+    /// See [`align`]
+    #[inline]
+    #[must_use]
+    pub const fn with_align(mut self, align: char) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn right(self) -> Self {
+        self.with_align(align::RIGHT)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn center(self) -> Self {
+        self.with_align(align::CENTER)
+    }
+
+    /// Fixed width in display columns instead of the width of the widest cell, wider cells are truncated
+    ///
+    /// A [`TableWriter`] can't look at the cells before writing them, so without this the column is as wide as the header.
+    #[inline]
+    #[must_use]
+    pub const fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Cells that are wider are truncated with an ellipsis, see [`Truncate`]
+    #[inline]
+    #[must_use]
+    pub const fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+}
+
+/// Writes a table row by row, so the rows don't have to be kept in memory
+///
+/// The widths of the columns have to be known before the first row, see [`Column::with_width`].
+/// Use a [`Table`] to compute them from the cells.
+pub struct TableWriter<'w, 'c, W>
+where
+    W: Write + ?Sized,
+{
+    writer: &'w mut W,
+    columns: &'c [Column<'c>],
+    style: Style,
+}
+
+impl<'w, 'c, W> TableWriter<'w, 'c, W>
+where
+    W: Write + ?Sized,
+{
+    /// Start the table by writing the header
+    pub fn new(
+        writer: &'w mut W,
+        columns: &'c [Column<'c>],
+        style: Style,
+    ) -> Result<Self, W::Error> {
+        let mut table = Self {
+            writer,
+            columns,
+            style,
+        };
+        table.write_rule(Rule::Top)?;
+        table.write_cells(columns.iter().map(|column| column.header))?;
+        table.write_rule(Rule::Header)?;
+        Ok(table)
+    }
+
+    /// Write a row, missing cells are empty and extra cells are ignored
+    pub fn row<I>(&mut self, cells: I) -> Result<(), W::Error>
+    where
+        I: IntoIterator,
+        I::Item: DynWriteTo,
+    {
+        self.write_cells(cells)
+    }
+
+    /// End the table, this only writes something for [`Style::Boxed`]
+    pub fn finish(mut self) -> Result<(), W::Error> {
+        self.write_rule(Rule::Bottom)
+    }
+
+    fn width(&self, column: &Column<'_>) -> usize {
+        let width = column
+            .width
+            .unwrap_or_else(|| Content::new(&column.header, self.style).measure().width);
+        let width = width.min(column.max_width);
+        // the rule below the header needs at least 3 chars for every alignment
+        if self.style == Style::Markdown {
+            width.max(3)
+        } else {
+            width
+        }
+    }
+
+    fn write_cells<I>(&mut self, cells: I) -> Result<(), W::Error>
+    where
+        I: IntoIterator,
+        I::Item: DynWriteTo,
+    {
+        let mut cells = cells.into_iter();
+        let (start, separator, end) = match self.style {
+            Style::Plain => ("", "  ", ""),
+            Style::Boxed => ("│ ", " │ ", " │"),
+            Style::Markdown => ("| ", " | ", " |"),
+            Style::Csv => ("", ",", ""),
+            Style::Tsv => ("", "\t", ""),
+        };
+        self.writer.write_str(start)?;
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                self.writer.write_str(separator)?;
+            }
+            let cell = cells.next();
+            let cell: &dyn DynWriteTo = match &cell {
+                Some(cell) => cell,
+                None => &"",
+            };
+            let content = Content::new(cell, self.style);
+            if self.style.is_aligned() {
+                // trailing spaces are left out at the end of the line
+                let pad_end = !end.is_empty() || i + 1 < self.columns.len();
+                write_aligned(
+                    self.writer,
+                    &content,
+                    self.width(column),
+                    column.align,
+                    pad_end,
+                )?;
+            } else {
+                content.write_to(self.writer)?;
+            }
+        }
+        self.writer.write_str(end)?;
+        self.writer.write_str("\n")
+    }
+
+    fn write_rule(&mut self, rule: Rule) -> Result<(), W::Error> {
+        let (start, line, separator, end) = match (self.style, rule) {
+            (Style::Boxed, Rule::Top) => ("┌", '─', "┬", "┐"),
+            (Style::Boxed, Rule::Header) => ("├", '─', "┼", "┤"),
+            (Style::Boxed, Rule::Bottom) => ("└", '─', "┴", "┘"),
+            (Style::Markdown, Rule::Header) => ("|", '-', "|", "|"),
+            _ => return Ok(()),
+        };
+        self.writer.write_str(start)?;
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                self.writer.write_str(separator)?;
+            }
+            let width = self.width(column);
+            if self.style == Style::Markdown {
+                let (left, right) = match column.align {
+                    align::RIGHT => (" ", ": "),
+                    align::CENTER => (" :", ": "),
+                    _ => (" ", " "),
+                };
+                self.writer.write_str(left)?;
+                // the colons take the place of dashes, so the rule is as wide as the cells
+                let colons = left.len() + right.len() - 2;
+                write_fill(self.writer, line, width - colons)?;
+                self.writer.write_str(right)?;
+            } else {
+                write_fill(self.writer, line, width + 2)?;
+            }
+        }
+        self.writer.write_str(end)?;
+        self.writer.write_str("\n")
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Rule {
+    Top,
+    Header,
+    Bottom,
+}
+
+/// A table with the rows kept in memory, so the columns are as wide as their widest cell
+///
+/// Widths are measured in display columns and ignore ANSI escape sequences, cells should be a single line.
+#[cfg(feature = "alloc")]
+pub struct Table<'t> {
+    columns: alloc::vec::Vec<Column<'t>>,
+    rows: alloc::vec::Vec<alloc::vec::Vec<alloc::boxed::Box<dyn DynWriteTo + 't>>>,
+    style: Style,
+}
+
+#[cfg(feature = "alloc")]
+impl<'t> Table<'t> {
+    #[must_use]
+    pub fn new<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = Column<'t>>,
+    {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: alloc::vec::Vec::new(),
+            style: Style::Plain,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Add a row, use `&dyn DynWriteTo` as the cells for mixed types
+    pub fn row<I>(&mut self, cells: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: DynWriteTo + 't,
+    {
+        self.rows.push(
+            cells
+                .into_iter()
+                .map(|cell| alloc::boxed::Box::new(cell) as alloc::boxed::Box<dyn DynWriteTo + 't>)
+                .collect(),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteTo for Table<'_> {
+    const ENDS_IN_NEWLINE: bool = true;
+
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        let columns: alloc::vec::Vec<Column<'_>> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                if column.width.is_some() || !self.style.is_aligned() {
+                    return *column;
+                }
+                let header_width = Content::new(&column.header, self.style).measure().width;
+                let width = self
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| Content::new(&**cell, self.style).measure().width)
+                    .fold(header_width, usize::max);
+                column.with_width(width)
+            })
+            .collect();
+        let mut table = TableWriter::new(w, &columns, self.style)?;
+        for row in &self.rows {
+            table.row(row.iter().map(|cell| &**cell))?;
+        }
+        table.finish()
+    }
+}
+
+/// A cell with the escaping of the style, without the quotes of CSV
+struct Content<'c> {
+    cell: &'c dyn DynWriteTo,
+    style: Style,
+}
+
+impl<'c> Content<'c> {
+    #[inline]
+    fn new(cell: &'c dyn DynWriteTo, style: Style) -> Self {
+        Self { cell, style }
+    }
+}
+
+impl WriteTo for Content<'_> {
+    fn write_to<W>(&self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        match self.style {
+            Style::Plain | Style::Boxed => self.cell.write_to(w),
+            Style::Markdown => self
+                .cell
+                .write_to(&mut Escape::<_, MarkdownCell>::new(&mut StripAnsi::new(w))),
+            Style::Tsv => self
+                .cell
+                .write_to(&mut Escape::<_, TsvCell>::new(&mut StripAnsi::new(w))),
+            Style::Csv => {
+                let mut needs_quotes = NeedsQuotes(false);
+                let Ok(()) = self.cell.write_to(&mut StripAnsi::new(&mut needs_quotes));
+                if !needs_quotes.0 {
+                    return self.cell.write_to(&mut StripAnsi::new(w));
+                }
+                w.write_str(<escape::Csv as Escaper>::PREFIX)?;
+                self.cell
+                    .write_to(&mut Escape::<_, escape::Csv>::new(&mut StripAnsi::new(
+                        &mut *w,
+                    )))?;
+                w.write_str(<escape::Csv as Escaper>::SUFFIX)
+            }
+        }
+    }
+}
+
+/// Truncate or pad `content` to `width` display columns
+fn write_aligned<W>(
+    w: &mut W,
+    content: &Content<'_>,
+    width: usize,
+    align: char,
+    pad_end: bool,
+) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+{
+    let content_width = content.measure().width;
+    if content_width > width {
+        let mut truncate = Truncate::new(&mut *w, width);
+        content.write_to(&mut truncate)?;
+        truncate.finish()?;
+        let fill = width.saturating_sub(truncate.width());
+        drop(truncate);
+        return if pad_end {
+            write_fill(w, ' ', fill)
+        } else {
+            Ok(())
+        };
+    }
+
+    let fill = width - content_width;
+    let (before, after) = match align {
+        align::RIGHT => (fill, 0),
+        align::CENTER => (fill / 2, fill - fill / 2),
+        _ => (0, fill),
+    };
+    write_fill(w, ' ', before)?;
+    content.write_to(w)?;
+    if pad_end {
+        write_fill(w, ' ', after)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if a CSV cell has to be quoted
+struct NeedsQuotes(bool);
+
+impl WriteInfallible for NeedsQuotes {
+    #[inline]
+    fn write_str_infallible(&mut self, s: &str) {
+        self.0 |= s.contains([',', '"', '\n', '\r']);
+    }
+}
+
+struct MarkdownCell;
+
+impl Escaper for MarkdownCell {
+    #[inline]
+    fn needs_escape(c: char) -> bool {
+        matches!(c, '|' | '\n' | '\r')
+    }
+
+    #[inline]
+    fn write_escaped<W>(c: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(match c {
+            '|' => "\\|",
+            '\n' => "<br>",
+            _ => "",
+        })
+    }
+}
+
+struct TsvCell;
+
+impl Escaper for TsvCell {
+    #[inline]
+    fn needs_escape(c: char) -> bool {
+        matches!(c, '\t' | '\n' | '\r' | '\\')
+    }
+
+    #[inline]
+    fn write_escaped<W>(c: char, w: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        w.write_str(match c {
+            '\t' => "\\t",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            _ => "\\\\",
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Column, Style, Table, TableWriter};
+    use crate::write_to::{DynWriteTo, WriteTo};
+
+    fn render(table: &Table<'_>) -> String {
+        let mut s = String::new();
+        let Ok(()) = table.write_to(&mut s);
+        s
+    }
+
+    #[test]
+    fn table() {
+        let red = "\x1B[38;5;9merror\x1B[39m";
+        let size = 12_345;
+        let mut table = Table::new([
+            Column::new("name"),
+            Column::new("size").right(),
+            Column::new("status").center(),
+            Column::new("note").with_max_width(8),
+        ]);
+        table.row(["a.txt", "7", "ok", "short"]).row([
+            &"b|c, \"d\"" as &dyn DynWriteTo,
+            &size,
+            &red,
+            &"much too long",
+        ]);
+
+        assert_eq!(
+            render(&table),
+            "name       size  status  note\n\
+             a.txt         7    ok    short\n\
+             b|c, \"d\"  12345  \x1B[38;5;9merror\x1B[39m   much to…\n"
+        );
+
+        let table = table.with_style(Style::Boxed);
+        assert_eq!(
+            render(&table),
+            "┌──────────┬───────┬────────┬──────────┐\n\
+             │ name     │  size │ status │ note     │\n\
+             ├──────────┼───────┼────────┼──────────┤\n\
+             │ a.txt    │     7 │   ok   │ short    │\n\
+             │ b|c, \"d\" │ 12345 │ \x1B[38;5;9merror\x1B[39m  │ much to… │\n\
+             └──────────┴───────┴────────┴──────────┘\n"
+        );
+
+        let table = table.with_style(Style::Markdown);
+        assert_eq!(
+            render(&table),
+            "| name      |  size | status | note     |\n\
+             | --------- | ----: | :----: | -------- |\n\
+             | a.txt     |     7 |   ok   | short    |\n\
+             | b\\|c, \"d\" | 12345 | error  | much to… |\n"
+        );
+
+        let table = table.with_style(Style::Csv);
+        assert_eq!(
+            render(&table),
+            "name,size,status,note\na.txt,7,ok,short\n\"b|c, \"\"d\"\"\",12345,error,much too long\n"
+        );
+
+        let mut table = table.with_style(Style::Tsv);
+        table.row(["tab\there", "back\\slash"]);
+        assert_eq!(
+            render(&table),
+            "name\tsize\tstatus\tnote\na.txt\t7\tok\tshort\nb|c, \"d\"\t12345\terror\tmuch too long\ntab\\there\tback\\\\slash\t\t\n"
+        );
+    }
+
+    #[test]
+    fn table_writer() {
+        let columns = [Column::new("id").right().with_width(3), Column::new("name")];
+        let mut s = String::new();
+        let Ok(mut table) = TableWriter::new(&mut s, &columns, Style::Boxed);
+        for (id, name) in [(1, "ab"), (42, "abcdef")] {
+            let Ok(()) = table.row([&id as &dyn DynWriteTo, &name]);
+        }
+        let Ok(()) = table.finish();
+        assert_eq!(
+            s,
+            "┌─────┬──────┐\n\
+             │  id │ name │\n\
+             ├─────┼──────┤\n\
+             │   1 │ ab   │\n\
+             │  42 │ abc… │\n\
+             └─────┴──────┘\n"
+        );
+    }
+}